@@ -4,3 +4,9 @@ male = männlich
 female = weiblich
 neutral = neutral
 other = anders
+undefined = unbestimmt
+
+# Ranks
+
+rank-captain = Hauptmann
+rank-major = Major
@@ -4,3 +4,9 @@ male = male
 female = female
 neutral = neutral
 other = other
+undefined = undefined
+
+# Ranks
+
+rank-captain = Captain
+rank-major = Major
@@ -7,11 +7,14 @@
 // Crates
 
 
-use std::hash::Hash;
-use std::fmt;
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::hash::Hash;
+use core::fmt;
+use core::str::FromStr;
 
 #[cfg( feature = "i18n" )] use fluent_templates::Loader;
-#[allow( unused )] use log::{error, warn, info, debug};
+#[cfg( feature = "log" )] #[allow( unused )] use log::{error, warn, info, debug};
 #[cfg( feature = "serde" )] use serde::{Serialize, Deserialize};
 use unic_langid::LanguageIdentifier;
 
@@ -27,13 +30,17 @@ use crate::name::NameError;
 
 
 /// A subset of possible genders.
+#[cfg_attr( feature = "arbitrary", derive( arbitrary::Arbitrary ) )]
 #[cfg_attr( feature = "serde", derive( Serialize, Deserialize ) )]
-#[derive( Clone, Copy, Hash, PartialEq, Eq, Debug )]
+#[derive( Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Debug )]
 pub enum Gender {
 	Male,
 	Female,
 	Neutral,
 	Other,
+
+	/// The gender is not known.
+	Undefined,
 }
 
 impl Gender {
@@ -43,20 +50,34 @@ impl Gender {
 	/// If the `lacle` is not supported, this method returns an error.
 	///
 	/// # Arguments
-	/// * `locale` the locale to use. Currently only English and German are supported.
+	/// * `locale` the locale to use. Currently only English, German, Dutch and Swedish are supported.
 	pub(crate) fn polite( &self, locale: &LanguageIdentifier ) -> Result<String, NameError> {
 		let res = match locale.language.as_str() {
 			"en" => match self {
 				Self::Male    => "Mister",
 				Self::Female  => "Miss",
-				Self::Neutral | Self::Other => return Err( NameError::NotExpressionable(
+				Self::Neutral | Self::Other | Self::Undefined => return Err( NameError::NotExpressionable(
 					format!( "Gender has no polite address: {}", self )
 				) ),
 			}
 			"de" => match self {
 				Self::Male    => "Herr",
 				Self::Female  => "Frau",
-				Self::Neutral | Self::Other => return Err( NameError::NotExpressionable(
+				Self::Neutral | Self::Other | Self::Undefined => return Err( NameError::NotExpressionable(
+					format!( "Gender has no polite address: {}", self )
+				) ),
+			}
+			"nl" => match self {
+				Self::Male    => "meneer",
+				Self::Female  => "mevrouw",
+				Self::Neutral | Self::Other | Self::Undefined => return Err( NameError::NotExpressionable(
+					format!( "Gender has no polite address: {}", self )
+				) ),
+			}
+			"sv" => match self {
+				Self::Male    => "Herr",
+				Self::Female  => "Fru",
+				Self::Neutral | Self::Other | Self::Undefined => return Err( NameError::NotExpressionable(
 					format!( "Gender has no polite address: {}", self )
 				) ),
 			}
@@ -66,6 +87,33 @@ impl Gender {
 		Ok( res.to_string() )
 	}
 
+	/// Returns `true` if a person of the respective gender has a polite address in the language of `locale`, so `polite()` does not return an error.
+	///
+	/// # Arguments
+	/// * `locale` the locale to use.
+	pub fn is_addressable( &self, locale: &LanguageIdentifier ) -> bool {
+		self.polite( locale ).is_ok()
+	}
+
+	/// Returns the polite address for a person of the respective gender like `polite()`, but uses `neutral` as address for the genders `Neutral` and `Other` instead of returning an error. This allows addressing non-binary persons politely. In English "Mx" is the established neutral address, German has no standard.
+	///
+	/// # Error
+	/// If the `locale` is not supported or the gender is `Undefined`, this method returns an error.
+	///
+	/// # Arguments
+	/// * `locale` the locale to use. Currently only English, German, Dutch and Swedish are supported.
+	/// * `neutral` the polite address used for the genders `Neutral` and `Other`. Bsp. "Mx"
+	pub fn polite_with_neutral( &self, locale: &LanguageIdentifier, neutral: &str ) -> Result<String, NameError> {
+		let ( Self::Neutral | Self::Other ) = self else {
+			return self.polite( locale );
+		};
+
+		match locale.language.as_str() {
+			"en" | "de" | "nl" | "sv" => Ok( neutral.to_string() ),
+			_ => Err( NameError::LangNotSupported( locale.to_string() ) ),
+		}
+	}
+
 	/// Returns the symbol representing the gender of `self`.
 	pub fn to_symbol( &self ) -> String {
 		let res = match self {
@@ -73,10 +121,24 @@ impl Gender {
 			Self::Female  => "♀",
 			Self::Neutral => "⚪",
 			Self::Other   => "⚧",
+			Self::Undefined => "⚪",
 		};
 
 		res.to_string()
 	}
+
+	/// Returns the gender represented by `symbol`. This is the inverse of `to_symbol()`. Since `Neutral` and `Undefined` share the symbol "⚪", this symbol always resolves to `Neutral`. If `symbol` is not known, this method returns `None`.
+	pub fn from_symbol( symbol: &str ) -> Option<Self> {
+		let res = match symbol {
+			"♂" => Self::Male,
+			"♀" => Self::Female,
+			"⚪" => Self::Neutral,
+			"⚧" => Self::Other,
+			_ => return None,
+		};
+
+		Some( res )
+	}
 }
 
 impl fmt::Display for Gender {
@@ -86,12 +148,36 @@ impl fmt::Display for Gender {
 			Self::Female  => "female",
 			Self::Neutral => "neutral",
 			Self::Other   => "other",
+			Self::Undefined => "undefined",
 		};
 
 		write!( f, "{}", res )
 	}
 }
 
+impl FromStr for Gender {
+	type Err = NameError;
+
+	fn from_str( s: &str ) -> Result<Self, Self::Err> {
+		let res = match s.to_lowercase().as_str() {
+			"male" => Self::Male,
+			"female" => Self::Female,
+			"neutral" => Self::Neutral,
+			"other" => Self::Other,
+			"undefined" => Self::Undefined,
+			_ => {
+				#[cfg( feature = "log" )]
+				error!( "{:?} is not a supported gender.", s );
+				return Err( NameError::NotExpressionable(
+					format!( "Unknown gender: {}", s )
+				) );
+			},
+		};
+
+		Ok( res )
+	}
+}
+
 #[cfg( feature = "i18n" )]
 impl DisplayLocale for Gender {
 	fn to_string_locale( &self, locale: &LanguageIdentifier ) -> String {
@@ -100,6 +186,7 @@ impl DisplayLocale for Gender {
 			Self::Female  => LOCALES.lookup( locale, "female" ),
 			Self::Neutral => LOCALES.lookup( locale, "neutral" ),
 			Self::Other   => LOCALES.lookup( locale, "other" ),
+			Self::Undefined => LOCALES.lookup( locale, "undefined" ),
 		}
 	}
 }
@@ -128,6 +215,43 @@ mod tests {
 		assert_eq!( Gender::Female.polite( &GERMAN ).unwrap(), "Frau".to_string() );
 		assert!( Gender::Neutral.polite( &GERMAN ).is_err() );
 		assert!( Gender::Other.polite( &GERMAN ).is_err() );
+		assert!( Gender::Undefined.polite( &GERMAN ).is_err() );
+		assert_eq!( Gender::Male.polite( &langid!( "nl-NL" ) ).unwrap(), "meneer".to_string() );
+		assert_eq!( Gender::Female.polite( &langid!( "nl-NL" ) ).unwrap(), "mevrouw".to_string() );
+		assert_eq!( Gender::Male.polite( &langid!( "sv-SE" ) ).unwrap(), "Herr".to_string() );
+		assert_eq!( Gender::Female.polite( &langid!( "sv-SE" ) ).unwrap(), "Fru".to_string() );
+		assert!( Gender::Neutral.polite( &langid!( "sv-SE" ) ).is_err() );
+	}
+
+	#[test]
+	fn gender_is_addressable() {
+		use unic_langid::langid;
+
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+
+		assert!( Gender::Male.is_addressable( &GERMAN ) );
+		assert!( Gender::Female.is_addressable( &GERMAN ) );
+		assert!( !Gender::Neutral.is_addressable( &GERMAN ) );
+		assert!( !Gender::Other.is_addressable( &GERMAN ) );
+		assert!( !Gender::Undefined.is_addressable( &GERMAN ) );
+		assert!( !Gender::Male.is_addressable( &langid!( "ja-JP" ) ) );
+	}
+
+	#[test]
+	fn gender_title_neutral() {
+		use unic_langid::langid;
+
+		const US_ENGLISH: LanguageIdentifier = langid!( "en-US" );
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+		const JAPANESE: LanguageIdentifier = langid!( "ja-JP" );
+
+		assert_eq!( Gender::Neutral.polite_with_neutral( &US_ENGLISH, "Mx" ).unwrap(), "Mx".to_string() );
+		assert_eq!( Gender::Other.polite_with_neutral( &US_ENGLISH, "Mx" ).unwrap(), "Mx".to_string() );
+		assert_eq!( Gender::Male.polite_with_neutral( &US_ENGLISH, "Mx" ).unwrap(), "Mister".to_string() );
+		assert_eq!( Gender::Neutral.polite_with_neutral( &GERMAN, "Ens." ).unwrap(), "Ens.".to_string() );
+		assert_eq!( Gender::Female.polite_with_neutral( &GERMAN, "Ens." ).unwrap(), "Frau".to_string() );
+		assert!( Gender::Undefined.polite_with_neutral( &GERMAN, "Ens." ).is_err() );
+		assert!( Gender::Neutral.polite_with_neutral( &JAPANESE, "Mx" ).is_err() );
 	}
 
 	#[test]
@@ -136,6 +260,17 @@ mod tests {
 		assert_eq!( Gender::Female.to_symbol(), "♀".to_string() );
 		assert_eq!( Gender::Neutral.to_symbol(), "⚪".to_string() );
 		assert_eq!( Gender::Other.to_symbol(), "⚧".to_string() );
+		assert_eq!( Gender::Undefined.to_symbol(), "⚪".to_string() );
+	}
+
+	#[test]
+	fn gender_from_symbol() {
+		assert_eq!( Gender::from_symbol( "♂" ), Some( Gender::Male ) );
+		assert_eq!( Gender::from_symbol( "♀" ), Some( Gender::Female ) );
+		assert_eq!( Gender::from_symbol( "⚪" ), Some( Gender::Neutral ) );
+		assert_eq!( Gender::from_symbol( "⚧" ), Some( Gender::Other ) );
+		assert_eq!( Gender::from_symbol( "male" ), None );
+		assert_eq!( Gender::from_symbol( "" ), None );
 	}
 
 	#[test]
@@ -144,5 +279,15 @@ mod tests {
 		assert_eq!( Gender::Female.to_string(), "female".to_string() );
 		assert_eq!( Gender::Neutral.to_string(), "neutral".to_string() );
 		assert_eq!( Gender::Other.to_string(), "other".to_string() );
+		assert_eq!( Gender::Undefined.to_string(), "undefined".to_string() );
+	}
+
+	#[test]
+	fn gender_from_str() {
+		assert_eq!( Gender::from_str( "male" ).unwrap(), Gender::Male );
+		assert_eq!( Gender::from_str( "Female" ).unwrap(), Gender::Female );
+		assert_eq!( Gender::from_str( "NEUTRAL" ).unwrap(), Gender::Neutral );
+		assert_eq!( Gender::from_str( "undefined" ).unwrap(), Gender::Undefined );
+		assert!( matches!( Gender::from_str( "unknown" ), Err( NameError::NotExpressionable( _ ) ) ) );
 	}
 }
@@ -9,6 +9,7 @@
 //! .rustdoc-hidden { display: none; }
 //! </style>
 #![doc = include_str!( "../README.md" )]
+#![cfg_attr( not( feature = "std" ), no_std )]
 
 
 
@@ -17,7 +18,10 @@
 // Crates
 
 
-#[cfg( feature = "i18n" )] use std::fmt;
+extern crate alloc;
+
+#[cfg( feature = "i18n" )] use alloc::string::String;
+#[cfg( feature = "i18n" )] use core::fmt;
 
 #[cfg( feature = "i18n" )] use unic_langid::LanguageIdentifier;
 
@@ -25,7 +29,7 @@ mod gender;
 pub use crate::gender::Gender;
 
 mod name;
-pub use crate::name::{NameError, GrammaticalCase, NameCombo, Names};
+pub use crate::name::{DesignateOptions, NameError, GrammaticalCase, InitialsStyle, NameCombo, Names, NamesBuilder, NicknameStyle, OrderStyle};
 
 
 
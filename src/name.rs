@@ -7,15 +7,61 @@
 // Crates
 
 
-use std::hash::Hash;
-use std::str::FromStr;
-
-#[allow( unused )] use log::{error, warn, info, debug};
-#[cfg( feature = "serde" )] use serde::{Serialize, Deserialize};
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::Hash;
+use core::str::FromStr;
+
+#[cfg( feature = "i18n" )] use fluent_templates::Loader;
+#[cfg( feature = "log" )] #[allow( unused )] use log::{error, warn, info, debug};
+#[cfg( feature = "serde" )] use serde::{Serialize, Deserialize, Deserializer};
 use thiserror::Error;
-use unic_langid::LanguageIdentifier;
+use unic_langid::{LanguageIdentifier, langid};
 
 use crate::Gender;
+#[cfg( feature = "i18n" )] use crate::LOCALES;
+
+
+
+
+//=============================================================================
+// Constants
+
+
+/// The locale used whenever a name has to be expressed without an explicitly given locale (e.g. by `Display`).
+const DEFAULT_LOCALE: LanguageIdentifier = langid!( "en-US" );
+
+/// The precedence of name combinations used by `Names::moniker()`.
+const MONIKER_PRECEDENCE: [NameCombo; 5] = [
+	NameCombo::Fullname,
+	NameCombo::Firstname,
+	NameCombo::Surname,
+	NameCombo::Nickname,
+	NameCombo::Supername,
+];
+
+/// The languages in which the surname customarily precedes the forenames (Hungarian, Japanese, Chinese and Korean).
+const SURNAME_FIRST_LANGUAGES: [&str; 4] = [ "hu", "ja", "zh", "ko" ];
+
+/// The name particles recognized by `Names::with_surname_parts()` at the beginning of a surname.
+const SURNAME_PARTICLES: [&str; 10] = [ "von", "van", "de", "der", "den", "di", "du", "la", "le", "zu" ];
+
+/// The prepositions that start an honorific name not preceded by an article. Bsp. "of" in "Eleanor of Aquitaine".
+const HONOR_PREPOSITIONS: [&str; 2] = [ "of", "von" ];
+
+/// The version marker of the compact format written by `Names::to_compact()`. It is the first field of every name in compact format.
+const COMPACT_VERSION: &str = "v1";
+
+/// The number of fields following the version marker in the compact format written by `Names::to_compact()`.
+const COMPACT_FIELDS: usize = 18;
+
+/// The name elements used by `NameCombo::Callsign` in the order of their precedence. Only one of them is required.
+const CALLSIGN_ELEMENTS: [&str; 3] = [ "nickname", "supername", "forenames" ];
 
 
 
@@ -32,9 +78,19 @@ pub enum NameError {
 	#[error( "This name combo is illegal." )]
 	IllegalCombo,
 
+	/// A required name element is missing. This is returned when checking the consistency of a name (`Names::validate()`, `NamesBuilder::build()` and `Names::from_compact()`) and by the methods trying several name combinations (`Names::moniker()`, `Names::moniker_with()` and `Names::designate_or()`), which list the elements missing for all of them separated by "/".
+	///
+	/// The methods designating a single name combination (Bsp. `Names::designate()`, `Names::designate_cow()`, `Names::designate_opts()` and `Names::designate_str()`) never return this variant since they report a missing name element as `NameError::CannotExpress` carrying the failing name combination. Code matching on this variant for the result of such a method has to match on `NameError::CannotExpress` instead.
 	#[error( "Name element missing: `{0}`" )]
 	MissingNameElement( String ),
 
+	/// A name element required by the name combination `combo` is missing. This is returned by the methods designating a single name combination instead of `NameError::MissingNameElement`.
+	#[error( "Name combination `{combo}` cannot be expressed, name element missing: `{missing}`" )]
+	CannotExpress {
+		combo: NameCombo,
+		missing: String,
+	},
+
 	#[error( "Name cannot be expressed: `{0}`" )]
 	NotExpressionable( String ),
 
@@ -49,39 +105,191 @@ pub enum NameError {
 // Helper functions
 
 
-/// Creating initials from `text` by only taking the first letter of each word and adding a dot after it.
+/// Creating initials from `text` by only taking the first letter of each word and adding a dot after it. Hyphenated words are abbreviated segment by segment. The first letter of each word is uppercased (a letter may expand to several letters, like "ß" => "SS"), only known name particles keep their lowercase letter.
 ///
 /// Bsp. "Thomas von Würzinger" => "T. v. W."
+/// Bsp. "Müller-Lüdenscheidt" => "M.-L."
+/// Bsp. "éric" => "É."
 fn initials( text: &str ) -> String {
 	if text.is_empty() {
 		return "".to_string();
 	}
 
 	text.split( ' ' )
-		.map( |x| format!( "{}.", x.chars().next().unwrap() ) )
+		.filter( |x| !x.is_empty() )
+		.map( |x| if SURNAME_PARTICLES.contains( &x ) {
+			particle_initials( x )
+		} else {
+			x.split( '-' )
+				.filter_map( |y| y.chars().next() )
+				.map( |y| format!( "{}.", y.to_uppercase() ) )
+				.collect::<Vec<String>>()
+				.join( "-" )
+		} )
+		.collect::<Vec<String>>()
+		.join( " " )
+}
+
+
+/// Creating initials from the name particles in `text` like `initials()`, but keeping the letters as they are.
+///
+/// Bsp. "von" => "v."
+fn particle_initials( text: &str ) -> String {
+	text.split( ' ' )
+		.filter( |x| !x.is_empty() )
+		.map( |x| x.split( '-' )
+			.filter_map( |y| y.chars().next() )
+			.map( |y| format!( "{}.", y ) )
+			.collect::<Vec<String>>()
+			.join( "-" )
+		)
 		.collect::<Vec<String>>()
 		.join( " " )
 }
 
 
+/// Replaces letters with diacritics in `text` by their base letters to allow for a locale-naive comparison of names. The German umlauts and "ß" are replaced according to the German phone book rule ("ä" => "ae", "ö" => "oe", "ü" => "ue", "ß" => "ss"), all other letters lose their diacritics ("é" => "e"). Letters not known to this function are kept as they are.
+fn fold_diacritics( text: &str ) -> String {
+	let mut res = String::with_capacity( text.len() );
+
+	for glyph in text.chars() {
+		let folded = match glyph {
+			'ä' => "ae",
+			'ö' => "oe",
+			'ü' => "ue",
+			'Ä' => "Ae",
+			'Ö' => "Oe",
+			'Ü' => "Ue",
+			'ß' => "ss",
+			'æ' => "ae",
+			'Æ' => "Ae",
+			'œ' => "oe",
+			'Œ' => "Oe",
+			'à' | 'á' | 'â' | 'ã' | 'å' => "a",
+			'À' | 'Á' | 'Â' | 'Ã' | 'Å' => "A",
+			'ç' => "c",
+			'Ç' => "C",
+			'è' | 'é' | 'ê' | 'ë' => "e",
+			'È' | 'É' | 'Ê' | 'Ë' => "E",
+			'ì' | 'í' | 'î' | 'ï' => "i",
+			'Ì' | 'Í' | 'Î' | 'Ï' => "I",
+			'ñ' => "n",
+			'Ñ' => "N",
+			'ò' | 'ó' | 'ô' | 'õ' | 'ø' => "o",
+			'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ø' => "O",
+			'ù' | 'ú' | 'û' => "u",
+			'Ù' | 'Ú' | 'Û' => "U",
+			'ý' | 'ÿ' => "y",
+			'Ý' => "Y",
+			_ => {
+				res.push( glyph );
+				continue;
+			},
+		};
+		res.push_str( folded );
+	}
+
+	res
+}
+
+
+/// Returns `text` without leading and trailing whitespace, or `None` if nothing remains. Bsp. " Penelope " => "Penelope", "  " => `None`
+fn non_empty( text: &str ) -> Option<String> {
+	let text = text.trim();
+	if text.is_empty() {
+		return None;
+	}
+	Some( text.to_string() )
+}
+
+
+/// Converts `text` into uppercase letters according to the casing rules of `locale`. Bsp. "Würzi" => "WÜRZI". In Turkish and Azerbaijani, the dotted "i" becomes "İ".
+fn uppercase_locale( text: &str, locale: &LanguageIdentifier ) -> String {
+	match locale.language.as_str() {
+		"tr" | "az" => text.replace( 'i', "İ" ).to_uppercase(),
+		_ => text.to_uppercase(),
+	}
+}
+
+
+/// Collapses all runs of whitespace in `text` into a single space and removes leading and trailing whitespace. Bsp. " Penelope  von Würzinger" => "Penelope von Würzinger". If `text` does not contain superfluous whitespace, it is returned unchanged without allocating.
+fn collapse_whitespace( text: Cow<'_, str> ) -> Cow<'_, str> {
+	let is_collapsed = !text.starts_with( char::is_whitespace )
+		&& !text.ends_with( char::is_whitespace )
+		&& !text.contains( "  " )
+		&& text.chars().all( |x| x == ' ' || !x.is_whitespace() );
+
+	if is_collapsed {
+		return text;
+	}
+
+	Cow::Owned( text.split_whitespace().collect::<Vec<&str>>().join( " " ) )
+}
+
+
+/// Returns `nickname` enclosed in the delimiters according to `style`. Bsp. "Würzi" => "(Würzi)" for `NicknameStyle::Parenthesized`.
+fn nickname_styled( nickname: &str, style: NicknameStyle ) -> String {
+	match style {
+		NicknameStyle::Bare => nickname.to_string(),
+		NicknameStyle::DoubleQuoted => format!( "\"{}\"", nickname ),
+		NicknameStyle::Parenthesized => format!( "({})", nickname ),
+	}
+}
+
+
+/// Returns `text` with its first letter in uppercase. Bsp. "die" => "Die"
+fn capitalize( text: &str ) -> String {
+	let mut glyphs = text.chars();
+	match glyphs.next() {
+		Some( x ) => x.to_uppercase().chain( glyphs ).collect(),
+		None => String::new(),
+	}
+}
+
+
 /// Adding letters to `text` depending on the grammatical case. `text` is assumed to be of the nominative case.
 ///
+/// Some languages do not change the writing of a name depending on the grammatical case (French and Italian use a preposition like "de" or "di" instead). For these languages, `text` is returned unchanged in all cases. The same is true for Arabic and Russian, whose declension of names is not yet supported.
+///
 /// # Arguments
 /// * `text` the text to modify depending on grammatical case.
 /// * `case` the grammatical case.
-/// * `locale` the locale to use the grammatical rules of. Currently only English and German are supported.
-fn add_case_letter( text: &str, case: GrammaticalCase, locale: &LanguageIdentifier ) -> Result<String, NameError> {
-	// In the currently supported languages (English and German), only the genetive case is changing the writing of a name.
+/// * `locale` the locale to use the grammatical rules of. Currently only Arabic, English, German, Dutch, French, Greek, Italian, Polish, Russian and Swedish are supported.
+fn add_case_letter<'a>( text: impl Into<Cow<'a, str>>, case: GrammaticalCase, locale: &LanguageIdentifier ) -> Result<Cow<'a, str>, NameError> {
+	add_case_letter_gendered( text, case, locale, None )
+}
+
+
+/// Works like [`add_case_letter`] but respects the `gender` of the person for languages that decline names depending on gender (Bsp. Polish "Kowalski" => "Kowalskiego" but "Kowalska" => "Kowalskiej"). If `gender` is `None`, the gender is guessed from the ending of `text`.
+///
+/// # Arguments
+/// * `text` the text to modify depending on grammatical case.
+/// * `case` the grammatical case.
+/// * `locale` the locale to use the grammatical rules of.
+/// * `gender` the gender of the person `text` is the name of.
+fn add_case_letter_gendered<'a>( text: impl Into<Cow<'a, str>>, case: GrammaticalCase, locale: &LanguageIdentifier, gender: Option<Gender> ) -> Result<Cow<'a, str>, NameError> {
+	let text = text.into();
+
+	if locale.language.as_str() == "pl" {
+		return Ok( decline_polish( text, case, gender ) );
+	}
+
+	if locale.language.as_str() == "el" {
+		return Ok( decline_greek( text, case ) );
+	}
+
+	// In the other supported languages (Arabic, English, German, Dutch, French, Italian, Russian and Swedish), only the genetive case is changing the writing of a name.
 	let GrammaticalCase::Genetive = case else {
-		return Ok( text.to_string() );
+		return Ok( text );
 	};
 
-	if text.is_empty() {
-		return Ok( "".to_string() );
-	}
+	// Trailing characters that are no letters (Bsp. quotes, parentheses or the period of an abbreviation) are ignored when choosing the case letter, which is inserted before them. English keeps the case letter after the period of an abbreviation (Bsp. "Jr.'s").
+	let core = text.trim_end_matches( |x: char| !( x.is_alphanumeric() || locale.language.as_str() == "en" && x == '.' ) );
 
-	let glyph_last = text.chars()
-		.last().unwrap()
+	let Some( glyph_last ) = core.chars().last() else {
+		return Ok( text );
+	};
+	let glyph_last = glyph_last
 		.to_lowercase()
 		.to_string();
 
@@ -94,10 +302,277 @@ fn add_case_letter( text: &str, case: GrammaticalCase, locale: &LanguageIdentifi
 			"s" | "ß" | "z" | "x" => "'",
 			_ => "s",
 		},
+		"nl" => match glyph_last.as_str() {
+			"s" | "x" | "z" => "'",
+			"a" | "e" | "i" | "o" | "u" | "y" => "'s",
+			_ => "s",
+		},
+		// Swedish marks the genetive without an apostrophe. Names already ending in a sibilant stay unchanged.
+		"sv" => match glyph_last.as_str() {
+			"s" | "x" | "z" => "",
+			_ => "s",
+		},
+		// These languages do not mark the genetive on the name itself.
+		"ar" | "fr" | "it" | "ru" => return Ok( text ),
+		_ => return Err( NameError::LangNotSupported( locale.to_string() ) ),
+	};
+
+	Ok( Cow::Owned( format!( "{}{}{}", core, appendix, &text[core.len()..] ) ) )
+}
+
+
+
+/// Adding letters to a spelled-out title or rank standing on its own depending on the grammatical case. Bsp. "Doktor" => "Doktors" in the German genetive.
+///
+/// A title or rank preceding a name is never inflected, since German marks the genetive only once, on the name (Bsp. "Doktor Würzingers"). Abbreviated titles ending in a period (Bsp. "Dr.") are never changed. Only German inflects a title in front of a name, so `text` is returned unchanged for all other languages. In German, feminine nouns do not take a case letter, so `text` is also returned unchanged for `Gender::Female`.
+///
+/// # Arguments
+/// * `text` the title or rank in the nominative case.
+/// * `case` the grammatical case.
+/// * `locale` the locale to use the grammatical rules of.
+/// * `gender` the gender of the person bearing the title or rank.
+fn inflect_title<'a>( text: impl Into<Cow<'a, str>>, case: GrammaticalCase, locale: &LanguageIdentifier, gender: Option<Gender> ) -> Result<Cow<'a, str>, NameError> {
+	let text = text.into();
+
+	if text.ends_with( '.' ) || gender == Some( Gender::Female ) || locale.language.as_str() != "de" {
+		return Ok( text );
+	}
+
+	add_case_letter( text, case, locale )
+}
+
+
+/// Declines Polish adjectival names ending in "-ski", "-cki" or "-dzki" (masculine) and "-ska", "-cka" or "-dzka" (feminine). All other names are returned unchanged, since the declension of Polish nouns is not yet supported.
+///
+/// # Arguments
+/// * `text` the name in the nominative case.
+/// * `case` the grammatical case.
+/// * `gender` the gender of the person. Masculine forms are not declined for `Gender::Female` and feminine forms are not declined for `Gender::Male`.
+fn decline_polish( text: Cow<'_, str>, case: GrammaticalCase, gender: Option<Gender> ) -> Cow<'_, str> {
+	let core = text.trim_end_matches( |x: char| !x.is_alphanumeric() );
+
+	let masculine = gender != Some( Gender::Female ) && [ "ski", "cki", "dzki" ].iter().any( |x| core.ends_with( x ) );
+	let feminine = gender != Some( Gender::Male ) && [ "ska", "cka", "dzka" ].iter().any( |x| core.ends_with( x ) );
+
+	let ending = match case {
+		GrammaticalCase::Genetive | GrammaticalCase::Accusative if masculine => "iego",
+		GrammaticalCase::Dative if masculine => "iemu",
+		GrammaticalCase::Genetive | GrammaticalCase::Dative if feminine => "iej",
+		GrammaticalCase::Accusative if feminine => "ą",
+		_ => return text,
+	};
+
+	// The last letter ("i" or "a") is replaced by the ending.
+	let stem = &core[..core.len() - 1];
+
+	Cow::Owned( format!( "{}{}{}", stem, ending, &text[core.len()..] ) )
+}
+
+
+/// Builds the genetive of Greek names transliterated into the Latin alphabet by replacing their ending: "-os" => "-ou" (Bsp. "Nikos" => "Nikou"), "-as" => "-a" (Bsp. "Loukas" => "Louka") and "-es" => "-ous" (Bsp. "Aristoteles" => "Aristotelous"). All other names and all other cases are returned unchanged.
+///
+/// # Arguments
+/// * `text` the name in the nominative case.
+/// * `case` the grammatical case.
+fn decline_greek( text: Cow<'_, str>, case: GrammaticalCase ) -> Cow<'_, str> {
+	let GrammaticalCase::Genetive = case else {
+		return text;
+	};
+
+	let core = text.trim_end_matches( |x: char| !x.is_alphanumeric() );
+
+	let ending = match core.get( core.len().saturating_sub( 2 ).. ).map( |x| x.to_lowercase() ).as_deref() {
+		Some( "os" ) => "ou",
+		Some( "as" ) => "a",
+		Some( "es" ) => "ous",
+		_ => return text,
+	};
+
+	// The last two letters are replaced by the ending.
+	let stem = &core[..core.len() - 2];
+
+	Cow::Owned( format!( "{}{}{}", stem, ending, &text[core.len()..] ) )
+}
+
+
+/// Escapes the delimiters of the compact format (see `Names::to_compact()`) in `text` by a preceding backslash. Bsp. `A|B` => `A\|B`
+fn compact_escape( text: &str ) -> String {
+	let mut res = String::with_capacity( text.len() );
+	for glyph in text.chars() {
+		if matches!( glyph, '\\' | '|' | ',' ) {
+			res.push( '\\' );
+		}
+		res.push( glyph );
+	}
+
+	res
+}
+
+
+/// Splits `text` at every `delim` that is not escaped by a preceding backslash. The escapes are kept in the parts, so they can be split again.
+fn compact_split( text: &str, delim: char ) -> Vec<&str> {
+	let mut res = Vec::new();
+	let mut start = 0;
+	let mut escaped = false;
+	for ( i, glyph ) in text.char_indices() {
+		if escaped {
+			escaped = false;
+		} else if glyph == '\\' {
+			escaped = true;
+		} else if glyph == delim {
+			res.push( &text[start..i] );
+			start = i + glyph.len_utf8();
+		}
+	}
+	res.push( &text[start..] );
+
+	res
+}
+
+
+/// Removes the escapes added by `compact_escape()` from `text`.
+fn compact_unescape( text: &str ) -> String {
+	let mut res = String::with_capacity( text.len() );
+	let mut escaped = false;
+	for glyph in text.chars() {
+		if glyph == '\\' && !escaped {
+			escaped = true;
+			continue;
+		}
+		escaped = false;
+		res.push( glyph );
+	}
+
+	res
+}
+
+
+/// Deserializes either a single string or a list of strings into a list of strings. This keeps data that stored only a single string (like the former `title`) readable.
+#[cfg( feature = "serde" )]
+fn deserialize_string_or_vec<'de, D>( deserializer: D ) -> Result<Vec<String>, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	#[derive( Deserialize )]
+	#[serde( untagged )]
+	enum StringOrVec {
+		String( String ),
+		Vec( Vec<String> ),
+	}
+
+	let res = match Option::<StringOrVec>::deserialize( deserializer )? {
+		Some( StringOrVec::String( x ) ) => vec![ x ],
+		Some( StringOrVec::Vec( x ) ) => x,
+		None => Vec::new(),
+	};
+
+	Ok( res )
+}
+
+
+
+
+/// Returns the marker preceding the birthname depending on `locale` and `gender`. Bsp. "geb." in German. English uses "né" for men and "née" for women. If the gender is neither male nor female or unknown, English uses "born".
+///
+/// # Arguments
+/// * `locale` the locale to use. Currently only English, German and Swedish are supported.
+/// * `gender` the gender of the person bearing the birthname.
+fn birthname_marker( locale: &LanguageIdentifier, gender: Option<Gender> ) -> Result<&'static str, NameError> {
+	let res = match locale.language.as_str() {
+		"en" => match gender {
+			Some( Gender::Male ) => "né",
+			Some( Gender::Female ) => "née",
+			_ => "born",
+		},
+		"de" => "geb.",
+		"sv" => "född",
 		_ => return Err( NameError::LangNotSupported( locale.to_string() ) ),
 	};
 
-	Ok( format!( "{}{}", text, appendix ) )
+	Ok( res )
+}
+
+
+/// Returns the value of the regnal number `text`, which is either a Roman numeral or written in digits. A trailing period is ignored. Bsp. "VIII" => 8, "VIII." => 8, "14" => 14. If `text` is no valid number, this returns `None`. This includes zero, numbers with a sign and Roman numerals not written in their canonical form (Bsp. "IIII" or "IXI") or too large to be written with Roman numerals.
+fn regnal_value( text: &str ) -> Option<u32> {
+	let text = text.trim_end_matches( '.' );
+	if !text.is_empty() && text.chars().all( |x| x.is_ascii_digit() ) {
+		return text.parse::<u32>().ok().filter( |x| *x > 0 );
+	}
+
+	let mut res: u32 = 0;
+	let mut previous = 0;
+	for glyph in text.chars().rev() {
+		let value = match glyph.to_ascii_uppercase() {
+			'I' => 1,
+			'V' => 5,
+			'X' => 10,
+			'L' => 50,
+			'C' => 100,
+			'D' => 500,
+			'M' => 1000,
+			_ => return None,
+		};
+		if value < previous {
+			res = res.checked_sub( value )?;
+		} else {
+			res = res.checked_add( value )?;
+			previous = value;
+		}
+	}
+
+	// Only the canonical form is accepted, which also rules out repeated or misplaced subtractions.
+	roman_numeral( res )
+		.filter( |x| x.eq_ignore_ascii_case( text ) )
+		.map( |_| res )
+}
+
+
+/// Returns `number` written as canonical Roman numeral. Bsp. 1994 => "MCMXCIV". Only the numbers 1 to 3999 can be written with Roman numerals. For all other numbers, this returns `None`.
+fn roman_numeral( number: u32 ) -> Option<String> {
+	const NUMERALS: [( u32, &str ); 13] = [
+		( 1000, "M" ), ( 900, "CM" ), ( 500, "D" ), ( 400, "CD" ),
+		( 100, "C" ), ( 90, "XC" ), ( 50, "L" ), ( 40, "XL" ),
+		( 10, "X" ), ( 9, "IX" ), ( 5, "V" ), ( 4, "IV" ),
+		( 1, "I" ),
+	];
+
+	if !( 1..=3999 ).contains( &number ) {
+		return None;
+	}
+
+	let mut rest = number;
+	let mut res = String::new();
+	for ( value, numeral ) in NUMERALS {
+		while rest >= value {
+			res.push_str( numeral );
+			rest -= value;
+		}
+	}
+
+	Some( res )
+}
+
+
+/// Returns the English ordinal number `number` spelled out. Bsp. 2 => "Second". Only the numbers 1 to 20 are supported, since higher regnal numbers are very rare. For all other numbers, this returns `None`.
+fn ordinal_english( number: u32 ) -> Option<&'static str> {
+	const ORDINALS: [&str; 20] = [
+		"First", "Second", "Third", "Fourth", "Fifth", "Sixth", "Seventh", "Eighth", "Ninth", "Tenth",
+		"Eleventh", "Twelfth", "Thirteenth", "Fourteenth", "Fifteenth", "Sixteenth", "Seventeenth", "Eighteenth", "Nineteenth", "Twentieth",
+	];
+
+	ORDINALS.get( usize::try_from( number ).ok()?.checked_sub( 1 )? ).copied()
+}
+
+
+/// Returns the word connecting a given name with the name of the father in an Arabic name chain according to `gender`: "ibn" (son of) for men and "bint" (daughter of) for women. If the gender is missing or neither male nor female, this returns an error.
+fn nasab_connector( gender: Option<Gender> ) -> Result<&'static str, NameError> {
+	match gender {
+		Some( Gender::Male ) => Ok( "ibn" ),
+		Some( Gender::Female ) => Ok( "bint" ),
+		Some( x ) => Err( NameError::NotExpressionable( format!( "Gender has no name chain connector: {}", x ) ) ),
+		None => Err( NameError::MissingNameElement( "gender".to_string() ) ),
+	}
 }
 
 
@@ -108,24 +583,33 @@ fn add_case_letter( text: &str, case: GrammaticalCase, locale: &LanguageIdentifi
 
 
 /// The different grammatical cases.
+#[cfg_attr( feature = "arbitrary", derive( arbitrary::Arbitrary ) )]
+#[cfg_attr( feature = "serde", derive( Serialize, Deserialize ) )]
+#[cfg_attr( feature = "serde", serde( rename_all = "lowercase" ) )]
 #[derive( Clone, Copy, PartialEq, Eq, Debug )]
 pub enum GrammaticalCase {
 	Nominative,
 	Genetive,
 	Dative,
 	Accusative,
+
+	/// The case of addressing someone directly. English and German do not inflect names in the vocative and treat it like the nominative.
+	Vocative,
 }
 
+/// Parses the name of a grammatical case case-insensitively. Besides the full names, the abbreviations "n"/"nom", "g"/"gen"/"s", "d"/"dat", "a"/"acc" and "v"/"voc" are accepted.
 impl FromStr for GrammaticalCase {
 	type Err = NameError;
 
 	fn from_str( s: &str ) -> Result<Self, Self::Err> {
 		let res = match s.to_lowercase().as_str() {
-			"nominative" => Self::Nominative,
-			"genetive" | "s" => Self::Genetive,
-			"dative" => Self::Dative,
-			"accusative" => Self::Accusative,
+			"nominative" | "nom" | "n" => Self::Nominative,
+			"genetive" | "gen" | "g" | "s" => Self::Genetive,
+			"dative" | "dat" | "d" => Self::Dative,
+			"accusative" | "acc" | "a" => Self::Accusative,
+			"vocative" | "voc" | "v" => Self::Vocative,
 			_ => {
+				#[cfg( feature = "log" )]
 				error!( "{:?} is not a supported grammatical case.", s );
 				return Err( NameError::IllegalCase );
 			},
@@ -136,7 +620,50 @@ impl FromStr for GrammaticalCase {
 }
 
 
+/// The different ways of treating name particles (the predicate, Bsp. "von") when creating initials.
+#[derive( Clone, Copy, PartialEq, Eq, Default, Debug )]
+pub enum InitialsStyle {
+	/// Particles are abbreviated like all other names. Bsp.: "T. v. W."
+	#[default]
+	Full,
+
+	/// Particles are omitted. Bsp.: "T. W."
+	DropParticles,
+
+	/// Particles are kept in full. Bsp.: "T. von W."
+	KeepParticles,
+}
+
+
+/// The different positions of name particles (the predicate, Bsp. "von") when ordering names for alphabetization.
+#[derive( Clone, Copy, PartialEq, Eq, Default, Debug )]
+pub enum OrderStyle {
+	/// The predicate follows the forename, which is customary in German. Bsp.: "Würzinger, Penelope von"
+	#[default]
+	PredicateAfterForename,
+
+	/// The predicate is kept with the surname, which is customary in many library catalogs. Bsp.: "von Würzinger, Penelope"
+	PredicateBeforeSurname,
+}
+
+
+/// The different ways of delimiting a nickname that is combined with other names.
+#[derive( Clone, Copy, PartialEq, Eq, Default, Debug )]
+pub enum NicknameStyle {
+	/// The nickname is used without any delimiters. Bsp.: Thomas Würzi
+	#[default]
+	Bare,
+
+	/// The nickname is enclosed in double quotes. Bsp.: Thomas "Würzi"
+	DoubleQuoted,
+
+	/// The nickname is enclosed in parentheses. Bsp.: Thomas (Würzi)
+	Parenthesized,
+}
+
+
 /// The possible combination of names.
+#[cfg_attr( feature = "arbitrary", derive( arbitrary::Arbitrary ) )]
 #[cfg_attr( feature = "serde", derive( Serialize, Deserialize ) )]
 #[derive( Clone, Copy, PartialEq, Eq, Debug )]
 pub enum NameCombo {
@@ -144,6 +671,8 @@ pub enum NameCombo {
 	Name,
 
 	/// The full name. Bsp.: "Penelope Karin von Würzinger geb. Stauff"
+	///
+	/// Only the name in front of the birthname is inflected, the birthname following "geb." always stays in the nominative case. Bsp. genetive: "Penelope Karin von Würzingers geb. Stauff"
 	Fullname,
 
 	/// The first forename. Bsp.: "Thomas"
@@ -188,12 +717,21 @@ pub enum NameCombo {
 	/// Polite with title, first forename and surname. Bsp.: "Frau Dr. Penelope von Würzinger"
 	PoliteTitleName,
 
+	/// Polite with title and surname. Bsp.: "Frau Dr. von Würzinger"
+	PoliteTitleSurname,
+
+	/// Polite with title and full name. Bsp.: "Frau Dr. Penelope Karin von Würzinger geb. Stauff"
+	PoliteTitleFullname,
+
 	/// Bsp.: Hauptkommissar
 	Rank,
 
 	/// Bsp.: Herr Hauptkommissar
 	PoliteRank,
 
+	/// Polite with rank and surname. Bsp.: "Frau Majorin von Würzinger"
+	PoliteRankSurname,
+
 	/// Bsp.: Hauptkommissar Thomas von Würzinger
 	RankName,
 
@@ -209,30 +747,60 @@ pub enum NameCombo {
 	/// Bsp.: Majorin Dr. Penelope von Würzinger
 	RankTitleName,
 
+	/// Bsp.: Dr. Majorin Penelope von Würzinger
+	TitleRankName,
+
 	/// Bsp.: Würzi
 	Nickname,
 
 	/// Bsp.: Thomas Würzi
+	///
+	/// The nickname can be delimited by choosing a `NicknameStyle` in `DesignateOptions`.
 	FirstNickname,
 
+	/// Like `FirstNickname`, but with the nickname in double quotes. Bsp.: Thomas "Würzi"
+	FirstNicknameQuoted,
+
+	/// Like `FirstNickname`, but with the nickname in parentheses. Bsp.: Thomas (Würzi)
+	FirstNicknameParenthesized,
+
 	/// Bsp.: Würzi von Würzinger
 	NickSurname,
 
+	/// Surname followed by the nickname in quotes. Bsp.: von Würzinger 'Würzi'
+	SurnameNickname,
+
 	/// Only the honorific name. Bsp.: "Starke", "Große", "Dunkle"
 	Honor,
 
-	/// Honorific name with article. Bsp.: "Der Starke", "Die Große"
+	/// Honorific name with article. The article starts the designation and is therefore capitalized. Bsp.: "Der Starke", "Die Große", "The Great"
 	Honortitle,
 
-	/// Honor with first forename. Bsp.: "Penelope die Große"
+	/// Honor with first forename. The article follows the forename and is therefore lowercase. Bsp.: "Penelope die Große", "Alexander the Great"
+	///
+	/// An honorific name starting with a preposition is used without an article. Bsp.: "Eleanor of Aquitaine"
+	///
+	/// In English, only the end of the designation is inflected (Bsp. "Alexander the Great's"). In German, only the forename of a prepositional honorific name is inflected (Bsp. "Elisabeths von Thüringen"). An honorific name with article cannot be expressed in German cases other than the nominative, since the article and the adjective would have to be declined.
 	FirstHonorname,
 
+	/// Forename with the regnal number of a monarch. Bsp.: "Heinrich VIII.", "Elizabeth II"
+	Regnal,
+
+	/// Forename with the spelled out regnal number of a monarch. Currently only supported in English. Bsp.: "Elizabeth the Second"
+	RegnalSpelled,
+
 	/// Typical antique roman woman's name: Bsp.: Iunia Prima (feminized surname [father's name] Cognomen).
 	DuaNomina,
 
 	/// Typical antique roman man's name: Bsp.: Gaius Julius Caeser (firstname surname [father's name] Cognomen).
 	TriaNomina,
 
+	/// Typical Russian name: Bsp.: Ivan Ivanovich Petrov (firstname patronymic surname).
+	NamePatronymic,
+
+	/// Typical Arabic name chain: Bsp.: Ali ibn Hassan al-Farsi (firstname "ibn"/"bint" father's name surname).
+	Nasab,
+
 	/// The supername. Bsp.: Würzt-das-Essen
 	Supername,
 
@@ -242,6 +810,12 @@ pub enum NameCombo {
 	/// Name with supername between forename and surname. Bsp.: Thomas Würzt-das-Essen von Würzinger
 	SuperName,
 
+	/// Supername followed by the surname. Bsp.: Würzt-das-Essen von Würzinger
+	SupernameSurname,
+
+	/// The first available of nickname, supername and first forename in uppercase letters (e.g. as radio or gaming handle). This form is never inflected. Bsp.: WÜRZI
+	Callsign,
+
 	/// Polite form of supername. Bsp.: Herr Würzt-das-Essen
 	PoliteSupername,
 
@@ -249,22 +823,254 @@ pub enum NameCombo {
 	RankSupername,
 
 	/// Initials of firstname and surname. Bsp.: P. v. W.
+	///
+	/// The treatment of the predicate can be changed by choosing an `InitialsStyle` in `DesignateOptions`.
 	Initials,
 
+	/// Initials of firstname and surname without any name particles. Bsp.: P. W.
+	InitialsNoParticle,
+
 	/// Initials of all forenames with title and surname. Bsp.: Dr. P. K. v. W.
 	InitialsFull,
 
+	/// Title with initials of the first forename and surname. Bsp.: Dr. P. v. W.
+	TitleInitialsShort,
+
+	/// Initials of all forenames only. Bsp.: P. K.
+	ForenameInitials,
+
+	/// First forename with initials of the predicate and surname. Bsp.: Penelope v. W.
+	AnonymizedSurname,
+
+	/// First forename in full, the other forenames as initials and the full surname. Bsp.: Penelope K. A. von Würzinger
+	FirstnameMiddleInitials,
+
 	/// Surname with initials of forenames (e.g. for nameplates). Bsp.: Dr. P. K. v. Würzinger
 	Sign,
 
 	/// Surname first to have a sensible way of alphabetically ordering names. Bsp.: Würzinger, Penelope von
+	///
+	/// Only the surname is inflected. Bsp.: Würzingers, Penelope von (German genetive)
+	///
+	/// The position of the predicate can be changed by choosing an `OrderStyle` in `DesignateOptions`.
 	OrderedName,
 
+	/// Like `OrderedName`, but the predicate is kept with the surname as customary in library catalogs. Bsp.: von Würzinger, Penelope
+	OrderedNameLibrary,
+
+	/// Like `OrderedName`, but with all forenames and the birthname following the surname. Bsp.: Würzinger geb. Stauff, Penelope Karin von
+	OrderedFullname,
+
 	/// Like `Ordered`, only that the forenames are ignored. Bsp.: Würzinger, von
+	///
+	/// Like all ordered forms, this requires a surname. A predicate alone (Bsp. "von") is never designated.
 	OrderedSurname,
 
 	/// Like `orderedName`, only with title added. Bsp.: Würzinger, Dr. Penelope von
 	OrderedTitleName,
+
+	/// Surname followed by the first forename without a comma, as is customary in East Asia. The name is not changed by the grammatical case. Bsp.: Yamada Tarou
+	SurnameFirst,
+}
+
+impl NameCombo {
+	/// All possible name combinations in the order of their declaration.
+	pub const ALL: &'static [NameCombo] = &[
+		Self::Name,
+		Self::Fullname,
+		Self::Firstname,
+		Self::Forenames,
+		Self::Surname,
+		Self::Title,
+		Self::TitleName,
+		Self::TitleFirstname,
+		Self::TitleSurname,
+		Self::TitleFullname,
+		Self::Polite,
+		Self::PoliteName,
+		Self::PoliteFirstname,
+		Self::PoliteSurname,
+		Self::PoliteFullname,
+		Self::PoliteTitleName,
+		Self::PoliteTitleSurname,
+		Self::PoliteTitleFullname,
+		Self::Rank,
+		Self::PoliteRank,
+		Self::PoliteRankSurname,
+		Self::RankName,
+		Self::RankFirstname,
+		Self::RankSurname,
+		Self::RankFullname,
+		Self::RankTitleName,
+		Self::TitleRankName,
+		Self::Nickname,
+		Self::FirstNickname,
+		Self::FirstNicknameQuoted,
+		Self::FirstNicknameParenthesized,
+		Self::NickSurname,
+		Self::SurnameNickname,
+		Self::Honor,
+		Self::Honortitle,
+		Self::FirstHonorname,
+		Self::Regnal,
+		Self::RegnalSpelled,
+		Self::DuaNomina,
+		Self::TriaNomina,
+		Self::NamePatronymic,
+		Self::Nasab,
+		Self::Supername,
+		Self::FirstSupername,
+		Self::SuperName,
+		Self::SupernameSurname,
+		Self::Callsign,
+		Self::PoliteSupername,
+		Self::RankSupername,
+		Self::Initials,
+		Self::InitialsNoParticle,
+		Self::InitialsFull,
+		Self::TitleInitialsShort,
+		Self::ForenameInitials,
+		Self::AnonymizedSurname,
+		Self::FirstnameMiddleInitials,
+		Self::Sign,
+		Self::OrderedName,
+		Self::OrderedNameLibrary,
+		Self::OrderedFullname,
+		Self::OrderedSurname,
+		Self::OrderedTitleName,
+		Self::SurnameFirst,
+	];
+
+	/// Returns the names of the name elements that are required to express a name in the form of `self`. The names of the elements are the same as used by `NameError::MissingNameElement`.
+	fn required_elements( &self ) -> &'static [&'static str] {
+		match self {
+			Self::Name
+			| Self::Fullname
+			| Self::Initials
+			| Self::InitialsNoParticle
+			| Self::InitialsFull
+			| Self::AnonymizedSurname
+			| Self::FirstnameMiddleInitials
+			| Self::Sign => &[ "forenames", "surname" ],
+			Self::Firstname | Self::Forenames | Self::ForenameInitials => &[ "forenames" ],
+			Self::Surname
+			| Self::OrderedName
+			| Self::OrderedNameLibrary
+			| Self::OrderedFullname
+			| Self::OrderedSurname
+			| Self::OrderedTitleName => &[ "surname" ],
+			Self::Title => &[ "title" ],
+			Self::TitleName | Self::TitleFullname | Self::TitleInitialsShort => &[ "title", "forenames", "surname" ],
+			Self::TitleFirstname => &[ "title", "forenames" ],
+			Self::TitleSurname => &[ "title", "surname" ],
+			Self::Polite => &[ "gender" ],
+			Self::PoliteName | Self::PoliteFullname => &[ "gender", "forenames", "surname" ],
+			Self::PoliteFirstname => &[ "gender", "forenames" ],
+			Self::PoliteSurname => &[ "gender", "surname" ],
+			Self::PoliteTitleName | Self::PoliteTitleFullname => &[ "gender", "title", "forenames", "surname" ],
+			Self::PoliteTitleSurname => &[ "gender", "title", "surname" ],
+			Self::Rank => &[ "rank" ],
+			Self::PoliteRank => &[ "gender", "rank" ],
+			Self::PoliteRankSurname => &[ "gender", "rank", "surname" ],
+			Self::RankName | Self::RankFullname => &[ "rank", "forenames", "surname" ],
+			Self::RankFirstname => &[ "rank", "forenames" ],
+			Self::RankSurname => &[ "rank", "surname" ],
+			Self::RankTitleName | Self::TitleRankName => &[ "rank", "title", "forenames", "surname" ],
+			Self::Nickname => &[ "nickname" ],
+			Self::FirstNickname | Self::FirstNicknameQuoted | Self::FirstNicknameParenthesized => &[ "forenames", "nickname" ],
+			Self::NickSurname | Self::SurnameNickname | Self::DuaNomina => &[ "nickname", "surname" ],
+			Self::Honor | Self::Honortitle => &[ "honorname" ],
+			Self::Regnal | Self::RegnalSpelled => &[ "forenames", "regnal" ],
+			Self::FirstHonorname => &[ "forenames", "honorname" ],
+			Self::TriaNomina => &[ "forenames", "nickname", "surname" ],
+			Self::NamePatronymic => &[ "forenames", "patronymic", "surname" ],
+			Self::Nasab => &[ "gender", "forenames", "patronymic", "surname" ],
+			Self::Supername => &[ "supername" ],
+			Self::FirstSupername => &[ "forenames", "supername" ],
+			Self::SuperName => &[ "forenames", "supername", "surname" ],
+			Self::SupernameSurname => &[ "supername", "surname" ],
+			Self::PoliteSupername => &[ "gender", "supername" ],
+			Self::RankSupername => &[ "rank", "supername" ],
+			Self::SurnameFirst => &[ "surname", "forenames" ],
+			// Requires only one of `CALLSIGN_ELEMENTS`, which is checked separately.
+			Self::Callsign => &[],
+		}
+	}
+
+	/// Returns the textual representation of `self`. This is the same string that is accepted by `NameCombo::from_str()`.
+	pub fn as_str( &self ) -> &'static str {
+		match self {
+			Self::Name => "Name",
+			Self::Fullname => "Fullname",
+			Self::Firstname => "Firstname",
+			Self::Forenames => "Forenames",
+			Self::Surname => "Surname",
+			Self::Title => "Title",
+			Self::TitleName => "TitleName",
+			Self::TitleFirstname => "TitleFirstname",
+			Self::TitleSurname => "TitleSurname",
+			Self::TitleFullname => "TitleFullname",
+			Self::Polite => "Polite",
+			Self::PoliteName => "PoliteName",
+			Self::PoliteFirstname => "PoliteFirstname",
+			Self::PoliteSurname => "PoliteSurname",
+			Self::PoliteFullname => "PoliteFullname",
+			Self::PoliteTitleName => "PoliteTitleName",
+			Self::PoliteTitleSurname => "PoliteTitleSurname",
+			Self::PoliteTitleFullname => "PoliteTitleFullname",
+			Self::Rank => "Rank",
+			Self::PoliteRank => "PoliteRank",
+			Self::PoliteRankSurname => "PoliteRankSurname",
+			Self::RankName => "RankName",
+			Self::RankFirstname => "RankFirstname",
+			Self::RankSurname => "RankSurname",
+			Self::RankFullname => "RankFullname",
+			Self::RankTitleName => "RankTitleName",
+			Self::TitleRankName => "TitleRankName",
+			Self::Nickname => "Nickname",
+			Self::FirstNickname => "FirstNickname",
+			Self::FirstNicknameQuoted => "FirstNicknameQuoted",
+			Self::FirstNicknameParenthesized => "FirstNicknameParenthesized",
+			Self::NickSurname => "NickSurname",
+			Self::SurnameNickname => "SurnameNickname",
+			Self::Honor => "Honor",
+			Self::Honortitle => "Honortitle",
+			Self::FirstHonorname => "FirstHonorname",
+			Self::Regnal => "Regnal",
+			Self::RegnalSpelled => "RegnalSpelled",
+			Self::DuaNomina => "DuaNomina",
+			Self::TriaNomina => "TriaNomina",
+			Self::NamePatronymic => "NamePatronymic",
+			Self::Nasab => "Nasab",
+			Self::Supername => "Supername",
+			Self::FirstSupername => "FirstSupername",
+			Self::SuperName => "SuperName",
+			Self::SupernameSurname => "SupernameSurname",
+			Self::Callsign => "Callsign",
+			Self::PoliteSupername => "PoliteSupername",
+			Self::RankSupername => "RankSupername",
+			Self::Initials => "Initials",
+			Self::InitialsNoParticle => "InitialsNoParticle",
+			Self::InitialsFull => "InitialsFull",
+			Self::TitleInitialsShort => "TitleInitialsShort",
+			Self::ForenameInitials => "ForenameInitials",
+			Self::AnonymizedSurname => "AnonymizedSurname",
+			Self::FirstnameMiddleInitials => "FirstnameMiddleInitials",
+			Self::Sign => "Sign",
+			Self::OrderedName => "OrderedName",
+			Self::OrderedNameLibrary => "OrderedNameLibrary",
+			Self::OrderedFullname => "OrderedFullname",
+			Self::OrderedSurname => "OrderedSurname",
+			Self::OrderedTitleName => "OrderedTitleName",
+			Self::SurnameFirst => "SurnameFirst",
+		}
+	}
+}
+
+impl fmt::Display for NameCombo {
+	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result {
+		write!( f, "{}", self.as_str() )
+	}
 }
 
 impl FromStr for NameCombo {
@@ -288,33 +1094,55 @@ impl FromStr for NameCombo {
 			"PoliteSurname" => Self::PoliteSurname,
 			"PoliteFullname" => Self::PoliteFullname,
 			"PoliteTitleName" => Self::PoliteTitleName,
-			"Rank" => Self::Rank,
+			"PoliteTitleSurname" => Self::PoliteTitleSurname,
+			"PoliteTitleFullname" => Self::PoliteTitleFullname,
+			"Rank" | "RankOnly" => Self::Rank,
 			"PoliteRank" => Self::PoliteRank,
+			"PoliteRankSurname" => Self::PoliteRankSurname,
 			"RankName" => Self::RankName,
 			"RankFirstname" => Self::RankFirstname,
 			"RankSurname" => Self::RankSurname,
 			"RankFullname" => Self::RankFullname,
 			"RankTitleName" => Self::RankTitleName,
+			"TitleRankName" => Self::TitleRankName,
 			"Nickname" => Self::Nickname,
 			"FirstNickname" => Self::FirstNickname,
+			"FirstNicknameQuoted" => Self::FirstNicknameQuoted,
+			"FirstNicknameParenthesized" => Self::FirstNicknameParenthesized,
 			"NickSurname" => Self::NickSurname,
+			"SurnameNickname" => Self::SurnameNickname,
 			"Honor" => Self::Honor,
 			"Honortitle" => Self::Honortitle,
 			"FirstHonorname" => Self::FirstHonorname,
+			"Regnal" => Self::Regnal,
+			"RegnalSpelled" => Self::RegnalSpelled,
 			"DuaNomina" => Self::DuaNomina,
 			"TriaNomina" => Self::TriaNomina,
+			"NamePatronymic" => Self::NamePatronymic,
+			"Nasab" => Self::Nasab,
 			"Supername" => Self::Supername,
 			"FirstSupername" => Self::FirstSupername,
 			"SuperName" => Self::SuperName,
+			"SupernameSurname" => Self::SupernameSurname,
+			"Callsign" => Self::Callsign,
 			"PoliteSupername" => Self::PoliteSupername,
 			"RankSupername" => Self::RankSupername,
 			"Initials" => Self::Initials,
+			"InitialsNoParticle" => Self::InitialsNoParticle,
 			"InitialsFull" => Self::InitialsFull,
+			"TitleInitialsShort" => Self::TitleInitialsShort,
+			"ForenameInitials" => Self::ForenameInitials,
+			"AnonymizedSurname" => Self::AnonymizedSurname,
+			"FirstnameMiddleInitials" => Self::FirstnameMiddleInitials,
 			"Sign" => Self::Sign,
 			"OrderedName" => Self::OrderedName,
+			"OrderedNameLibrary" => Self::OrderedNameLibrary,
+			"OrderedFullname" => Self::OrderedFullname,
 			"OrderedSurname" => Self::OrderedSurname,
 			"OrderedTitleName" => Self::OrderedTitleName,
+			"SurnameFirst" => Self::SurnameFirst,
 			_ => {
+				#[cfg( feature = "log" )]
 				error!( "{:?} is not a supported name combination.", s );
 				return Err( NameError::IllegalCombo );
 			},
@@ -331,28 +1159,94 @@ impl FromStr for NameCombo {
 // Structs
 
 
+/// The options used by [`Names::designate_opts`] to designate a name.
+#[derive( Clone, PartialEq, Eq, Debug )]
+pub struct DesignateOptions {
+	/// The grammatical case. Defaults to `GrammaticalCase::Nominative`.
+	pub case: GrammaticalCase,
+
+	/// The locale to use the grammatical rules of. Defaults to English (`en-US`).
+	pub locale: LanguageIdentifier,
+
+	/// The treatment of name particles in initials (`NameCombo::Initials`, `NameCombo::InitialsFull`, `NameCombo::TitleInitialsShort`, `NameCombo::AnonymizedSurname` and `NameCombo::Sign`).
+	pub initials_style: InitialsStyle,
+
+	/// The delimiters of the nickname in `NameCombo::FirstNickname`.
+	pub nickname_style: NicknameStyle,
+
+	/// The position of the predicate in `NameCombo::OrderedName` and `NameCombo::OrderedFullname`.
+	pub order_style: OrderStyle,
+
+	/// If `true`, the name elements are used verbatim as they are stored: they are never inflected (regardless of `case`), feminized (see `Names::with_rank_feminize()` and `Names::with_surname_feminize()`) or recapitalized (like the Dutch predicate of a surname standing on its own). This is useful for names that are stored already in the desired form. Defaults to `false`.
+	pub raw: bool,
+}
+
+impl DesignateOptions {
+	/// Create new options for the grammatical `case` and `locale`, using the default styles.
+	pub fn new( case: GrammaticalCase, locale: &LanguageIdentifier ) -> Self {
+		Self {
+			case,
+			locale: locale.clone(),
+			..Self::default()
+		}
+	}
+}
+
+impl Default for DesignateOptions {
+	fn default() -> Self {
+		Self {
+			case: GrammaticalCase::Nominative,
+			locale: DEFAULT_LOCALE,
+			initials_style: InitialsStyle::default(),
+			nickname_style: NicknameStyle::default(),
+			order_style: OrderStyle::default(),
+			raw: false,
+		}
+	}
+}
+
+
 /// The different names of a person that can be combined in various ways.
+///
+/// With the **`serde`** feature, the name elements are (de)serialized with camelCase keys (Bsp. "surnameSecond"). Older data using the snake_case keys of previous versions (Bsp. "surname_second") can still be deserialized, but is always serialized with camelCase keys.
+#[cfg_attr( feature = "arbitrary", derive( arbitrary::Arbitrary ) )]
 #[cfg_attr( feature = "serde", derive( Serialize, Deserialize ) )]
+#[cfg_attr( feature = "serde", serde( rename_all = "camelCase" ) )]
 #[derive( Clone, Hash, PartialEq, Eq, Default, Debug )]
 pub struct Names {
 	#[cfg_attr( feature = "serde", serde( default ) )]
 	forenames: Vec<String>,
 
+	#[cfg_attr( feature = "serde", serde( default, alias = "rufname_index" ) )]
+	rufname_index: Option<usize>,
+
+	#[cfg_attr( feature = "serde", serde( default ) )]
+	patronymic: Option<String>,
+
 	#[cfg_attr( feature = "serde", serde( default ) )]
 	predicate: Option<String>,
 
 	#[cfg_attr( feature = "serde", serde( default ) )]
 	surname: Option<String>,
 
+	#[cfg_attr( feature = "serde", serde( default, alias = "surname_second" ) )]
+	surname_second: Option<String>,
+
 	#[cfg_attr( feature = "serde", serde( default ) )]
-	birthname: Option<String>,
+	suffix: Option<String>,
 
 	#[cfg_attr( feature = "serde", serde( default ) )]
-	title: Option<String>,
+	birthname: Option<String>,
+
+	#[cfg_attr( feature = "serde", serde( default, alias = "title", deserialize_with = "deserialize_string_or_vec" ) )]
+	titles: Vec<String>,
 
 	#[cfg_attr( feature = "serde", serde( default ) )]
 	rank: Option<String>,
 
+	#[cfg_attr( feature = "serde", serde( default, alias = "rank_key" ) )]
+	rank_key: Option<String>,
+
 	#[cfg_attr( feature = "serde", serde( default ) )]
 	nickname: Option<String>,
 
@@ -362,86 +1256,627 @@ pub struct Names {
 	#[cfg_attr( feature = "serde", serde( default ) )]
 	supername: Option<String>,
 
+	#[cfg_attr( feature = "serde", serde( default ) )]
+	regnal: Option<String>,
+
 	#[cfg_attr( feature = "serde", serde( default ) )]
 	gender: Option<Gender>,
+
+	#[cfg_attr( feature = "serde", serde( default, alias = "rank_feminize" ) )]
+	rank_feminize: bool,
+
+	#[cfg_attr( feature = "serde", serde( default, alias = "surname_feminize" ) )]
+	surname_feminize: bool,
 }
 
 impl Names {
 	/// Create a new `Names`. No name is actually being set.
+	///
+	/// The `with_*` methods trim leading and trailing whitespace of the names they are given. A name that is empty or consists only of whitespace leaves the name element unset (Bsp. `with_surname( "  " )` results in no surname and `with_forenames( &[ "Penelope", "" ] )` only sets "Penelope").
 	pub fn new() -> Self {
 		Self::default()
 	}
 
-	/// Set the forenames.
+	/// Start building a `Names` that is checked for consistency when finished by `NamesBuilder::build()`. Bsp. `Names::builder().with_surname( "Würzinger" ).build()`.
+	pub fn builder() -> NamesBuilder {
+		NamesBuilder::default()
+	}
+
+	/// Checks `self` for consistency. The name elements that only amend the surname (the predicate, the second surname and the birthname) require a surname. If one of them is set without a surname, this method returns `NameError::MissingNameElement( "surname" )`. If the index of the Rufname does not point to one of the forenames, this method returns `NameError::NotExpressionable`.
+	pub fn validate( &self ) -> Result<(), NameError> {
+		let amends_surname = self.predicate.is_some() || self.surname_second.is_some() || self.birthname.is_some();
+		if amends_surname && self.surname.is_none() {
+			return Err( NameError::MissingNameElement( "surname".to_string() ) );
+		}
+
+		if !self.forenames.is_empty() {
+			self.rufname_res()?;
+		}
+
+		Ok( () )
+	}
+
+	/// Set the forenames.
 	pub fn with_forenames( mut self, names: &[&str] ) -> Self {
-		self.forenames = names.iter().map( |x| x.to_string() ).collect();
+		self.forenames = names.iter().filter_map( |x| non_empty( x ) ).collect();
+		self
+	}
+
+	/// Add a compound forename by joining `parts` with hyphens. The forename is appended to the forenames already present. Bsp. `[ "Jean", "Pierre" ]` => "Jean-Pierre". Empty parts are ignored. If all parts are empty, no forename is added.
+	pub fn with_hyphenated_forename( mut self, parts: &[&str] ) -> Self {
+		let name = parts.iter()
+			.filter_map( |x| non_empty( x ) )
+			.collect::<Vec<String>>()
+			.join( "-" );
+		if !name.is_empty() {
+			self.forenames.push( name );
+		}
+		self
+	}
+
+	/// Set the forenames from any iterator over strings, Bsp. a `Vec<String>`.
+	pub fn with_forenames_iter<I, S>( mut self, names: I ) -> Self
+	where
+		I: IntoIterator<Item = S>,
+		S: AsRef<str>,
+	{
+		self.forenames = names.into_iter().filter_map( |x| non_empty( x.as_ref() ) ).collect();
 		self
 	}
 
-	/// Set the predicate of a possible surname.
+	/// Add a forename after the forenames already present.
+	pub fn with_forename( mut self, name: &str ) -> Self {
+		self.forenames.extend( non_empty( name ) );
+		self
+	}
+
+	/// Set the index of the Rufname within the forenames. The Rufname is the forename a person is actually called by, which is not necessarily the first one (Bsp. "Karin" in "Penelope Karin von Würzinger" with the index 1). It is used by `NameCombo::Name`, `NameCombo::Firstname` and `NameCombo::PoliteFirstname` instead of the first forename. If no index is set, the first forename is used.
+	pub fn with_rufname_index( mut self, index: usize ) -> Self {
+		self.rufname_index = Some( index );
+		self
+	}
+
+	/// Set the patronymic. Bsp. "Ivanovich" in "Ivan Ivanovich Petrov".
+	pub fn with_patronymic( mut self, name: &str ) -> Self {
+		self.patronymic = non_empty( name );
+		self
+	}
+
+	/// Set the predicate of a possible surname. A predicate is only an addition to the surname and never used on its own, so a surname is required to designate it (see `validate()`). For a name consisting of a particle and a place, the place is the surname. Bsp. "von" and "Lichtenstein" in "Ulrich von Lichtenstein".
 	pub fn with_predicate( mut self, name: &str ) -> Self {
-		self.predicate = Some( name.to_string() );
+		self.predicate = non_empty( name );
 		self
 	}
 
 	/// Set the surname.
 	pub fn with_surname( mut self, name: &str ) -> Self {
-		self.surname = Some( name.to_string() );
+		self.surname = non_empty( name );
+		self
+	}
+
+	/// Set the surname and the predicate from a combined surname. Leading name particles written in lowercase ("von", "van", "de", "der", "den", "di", "du", "la", "le" and "zu") are used as predicate, the rest as surname. Bsp. "von der Leyen" => predicate "von der", surname "Leyen". The last word is always used as surname, even if it is a particle. If no particle is found, the predicate is not changed.
+	pub fn with_surname_parts( mut self, full: &str ) -> Self {
+		let words: Vec<&str> = full.split_whitespace().collect();
+		let count = words.iter()
+			.take( words.len().saturating_sub( 1 ) )
+			.take_while( |x| SURNAME_PARTICLES.contains( x ) )
+			.count();
+
+		if count > 0 {
+			self.predicate = Some( words[..count].join( " " ) );
+		}
+		self.surname = non_empty( &words[count..].join( " " ) );
+		self
+	}
+
+	/// Set the second surname. This is customary in Spanish speaking countries, where the surname of the father is followed by the surname of the mother. Bsp. "Márquez" in "Gabriel García Márquez".
+	pub fn with_second_surname( mut self, name: &str ) -> Self {
+		self.surname_second = non_empty( name );
+		self
+	}
+
+	/// Set the generational suffix. Bsp. "Jr.", "Sr.", "III".
+	pub fn with_suffix( mut self, suffix: &str ) -> Self {
+		self.suffix = non_empty( suffix );
 		self
 	}
 
 	/// Set the birthname.
 	pub fn with_birthname( mut self, name: &str ) -> Self {
-		self.birthname = Some( name.to_string() );
+		self.birthname = non_empty( name );
+		self
+	}
+
+	/// Change the surname because of a marriage. The current surname becomes the birthname, unless a birthname is already set (Bsp. due to an earlier marriage). The predicate is kept. Bsp. "Penelope Stauff" => "Penelope Würzinger geb. Stauff".
+	pub fn rename_marriage( mut self, new_surname: &str ) -> Self {
+		if self.birthname.is_none() {
+			self.birthname = self.surname.take();
+		}
+		self.surname = non_empty( new_surname );
 		self
 	}
 
-	/// Set the title.
+	/// Add a title. Multiple titles are kept in the order they have been added. Bsp. "Prof." and "Dr." result in "Prof. Dr.".
 	pub fn with_title( mut self, title: &str ) -> Self {
-		self.title = Some( title.to_string() );
+		self.titles.extend( non_empty( title ) );
+		self
+	}
+
+	/// Set the titles. This replaces all titles already present.
+	pub fn with_titles( mut self, titles: &[&str] ) -> Self {
+		self.titles = titles.iter().filter_map( |x| non_empty( x ) ).collect();
 		self
 	}
 
 	/// Set the rank.
 	pub fn with_rank( mut self, rank: &str ) -> Self {
-		self.rank = Some( rank.to_string() );
+		self.rank = non_empty( rank );
+		self
+	}
+
+	/// Set the key of the rank used for translating the rank. If the **`i18n`** feature is enabled, the rank is looked up by this key in the localization files. If the key is unknown, the rank set by `with_rank()` is used instead.
+	pub fn with_rank_key( mut self, key: &str ) -> Self {
+		self.rank_key = non_empty( key );
 		self
 	}
 
 	/// Set the nickname.
 	pub fn with_nickname( mut self, name: &str ) -> Self {
-		self.nickname = Some( name.to_string() );
+		self.nickname = non_empty( name );
 		self
 	}
 
 	/// Set the honorname.
 	pub fn with_honorname( mut self, name: &str ) -> Self {
-		self.honorname = Some( name.to_string() );
+		self.honorname = non_empty( name );
 		self
 	}
 
 	/// Set the supername.
 	pub fn with_supername( mut self, name: &str ) -> Self {
-		self.supername = Some( name.to_string() );
+		self.supername = non_empty( name );
+		self
+	}
+
+	/// Set the regnal number of a monarch, either as Roman numeral or in digits. Bsp. "VIII" in "Heinrich VIII.".
+	pub fn with_regnal( mut self, number: &str ) -> Self {
+		self.regnal = non_empty( number );
+		self
+	}
+
+	/// Set the gender. The gender can be given by value or by reference. Bsp. `with_gender( Gender::Female )` or `with_gender( &Gender::Female )`.
+	pub fn with_gender( mut self, gender: impl Borrow<Gender> ) -> Self {
+		self.gender = Some( *gender.borrow() );
 		self
 	}
 
-	/// Set the gender.
-	pub fn with_gender( mut self, gender: &Gender ) -> Self {
-		self.gender = Some( *gender );
+	/// Set whether the rank is feminized for women. If `feminize` is `true` and the gender is `Gender::Female`, the German rank gets the suffix "-in" unless it already ends with "in" (Bsp. "Major" => "Majorin"). This is off by default, so ranks already stored in their feminine form stay unchanged.
+	pub fn with_rank_feminize( mut self, feminize: bool ) -> Self {
+		self.rank_feminize = feminize;
 		self
 	}
 
+	/// Set whether the surname is feminized for women. If `feminize` is `true` and the gender is `Gender::Female`, the surname gets the Czech or Slovak feminine form in these languages (Bsp. "Novák" => "Nováková", "Novotný" => "Novotná"). This is off by default, so surnames already stored in their feminine form stay unchanged.
+	pub fn with_surname_feminize( mut self, feminize: bool ) -> Self {
+		self.surname_feminize = feminize;
+		self
+	}
+
+	/// Set the gender from its textual representation (Bsp. "female"). If `gender` is not a known gender, this method returns an error.
+	pub fn with_gender_str( self, gender: &str ) -> Result<Self, NameError> {
+		let gender = Gender::from_str( gender )?;
+		Ok( self.with_gender( gender ) )
+	}
+
+	/// Add a forename after the forenames already present if `name` is `Some`, like `with_forename()`. If `name` is `None`, `self` is returned unchanged.
+	pub fn with_forename_opt( self, name: Option<&str> ) -> Self {
+		match name {
+			Some( x ) => self.with_forename( x ),
+			None => self,
+		}
+	}
+
+	/// Set the patronymic if `name` is `Some`, like `with_patronymic()`. If `name` is `None`, `self` is returned unchanged.
+	pub fn with_patronymic_opt( self, name: Option<&str> ) -> Self {
+		match name {
+			Some( x ) => self.with_patronymic( x ),
+			None => self,
+		}
+	}
+
+	/// Set the predicate if `name` is `Some`, like `with_predicate()`. If `name` is `None`, `self` is returned unchanged.
+	pub fn with_predicate_opt( self, name: Option<&str> ) -> Self {
+		match name {
+			Some( x ) => self.with_predicate( x ),
+			None => self,
+		}
+	}
+
+	/// Set the surname if `name` is `Some`, like `with_surname()`. If `name` is `None`, `self` is returned unchanged.
+	pub fn with_surname_opt( self, name: Option<&str> ) -> Self {
+		match name {
+			Some( x ) => self.with_surname( x ),
+			None => self,
+		}
+	}
+
+	/// Set the second surname if `name` is `Some`, like `with_second_surname()`. If `name` is `None`, `self` is returned unchanged.
+	pub fn with_second_surname_opt( self, name: Option<&str> ) -> Self {
+		match name {
+			Some( x ) => self.with_second_surname( x ),
+			None => self,
+		}
+	}
+
+	/// Set the generational suffix if `suffix` is `Some`, like `with_suffix()`. If `suffix` is `None`, `self` is returned unchanged.
+	pub fn with_suffix_opt( self, suffix: Option<&str> ) -> Self {
+		match suffix {
+			Some( x ) => self.with_suffix( x ),
+			None => self,
+		}
+	}
+
+	/// Set the birthname if `name` is `Some`, like `with_birthname()`. If `name` is `None`, `self` is returned unchanged.
+	pub fn with_birthname_opt( self, name: Option<&str> ) -> Self {
+		match name {
+			Some( x ) => self.with_birthname( x ),
+			None => self,
+		}
+	}
+
+	/// Add a title if `title` is `Some`, like `with_title()`. If `title` is `None`, `self` is returned unchanged.
+	pub fn with_title_opt( self, title: Option<&str> ) -> Self {
+		match title {
+			Some( x ) => self.with_title( x ),
+			None => self,
+		}
+	}
+
+	/// Set the rank if `rank` is `Some`, like `with_rank()`. If `rank` is `None`, `self` is returned unchanged.
+	pub fn with_rank_opt( self, rank: Option<&str> ) -> Self {
+		match rank {
+			Some( x ) => self.with_rank( x ),
+			None => self,
+		}
+	}
+
+	/// Set the key of the rank if `key` is `Some`, like `with_rank_key()`. If `key` is `None`, `self` is returned unchanged.
+	pub fn with_rank_key_opt( self, key: Option<&str> ) -> Self {
+		match key {
+			Some( x ) => self.with_rank_key( x ),
+			None => self,
+		}
+	}
+
+	/// Set the nickname if `name` is `Some`, like `with_nickname()`. If `name` is `None`, `self` is returned unchanged.
+	pub fn with_nickname_opt( self, name: Option<&str> ) -> Self {
+		match name {
+			Some( x ) => self.with_nickname( x ),
+			None => self,
+		}
+	}
+
+	/// Set the honorname if `name` is `Some`, like `with_honorname()`. If `name` is `None`, `self` is returned unchanged.
+	pub fn with_honorname_opt( self, name: Option<&str> ) -> Self {
+		match name {
+			Some( x ) => self.with_honorname( x ),
+			None => self,
+		}
+	}
+
+	/// Set the supername if `name` is `Some`, like `with_supername()`. If `name` is `None`, `self` is returned unchanged.
+	pub fn with_supername_opt( self, name: Option<&str> ) -> Self {
+		match name {
+			Some( x ) => self.with_supername( x ),
+			None => self,
+		}
+	}
+
+	/// Set the regnal number if `number` is `Some`, like `with_regnal()`. If `number` is `None`, `self` is returned unchanged.
+	pub fn with_regnal_opt( self, number: Option<&str> ) -> Self {
+		match number {
+			Some( x ) => self.with_regnal( x ),
+			None => self,
+		}
+	}
+
+	/// Set the gender if `gender` is `Some`, like `with_gender()`. If `gender` is `None`, `self` is returned unchanged.
+	pub fn with_gender_opt( self, gender: Option<Gender> ) -> Self {
+		match gender {
+			Some( x ) => self.with_gender( x ),
+			None => self,
+		}
+	}
+
+	/// Set the forenames in place. This replaces all forenames already present.
+	pub fn set_forenames( &mut self, names: &[&str] ) {
+		self.forenames = names.iter().filter_map( |x| non_empty( x ) ).collect();
+	}
+
+	/// Set the index of the Rufname in place. `None` removes it, so the first forename is used. See `with_rufname_index()`.
+	pub fn set_rufname_index( &mut self, index: Option<usize> ) {
+		self.rufname_index = index;
+	}
+
+	/// Set the patronymic in place. An empty `name` removes it.
+	pub fn set_patronymic( &mut self, name: &str ) {
+		self.patronymic = non_empty( name );
+	}
+
+	/// Set the predicate of the surname in place. An empty `name` removes it.
+	pub fn set_predicate( &mut self, name: &str ) {
+		self.predicate = non_empty( name );
+	}
+
+	/// Set the surname in place. An empty `name` removes it.
+	pub fn set_surname( &mut self, name: &str ) {
+		self.surname = non_empty( name );
+	}
+
+	/// Set the second surname in place. An empty `name` removes it.
+	pub fn set_second_surname( &mut self, name: &str ) {
+		self.surname_second = non_empty( name );
+	}
+
+	/// Set the generational suffix in place. An empty `name` removes it.
+	pub fn set_suffix( &mut self, name: &str ) {
+		self.suffix = non_empty( name );
+	}
+
+	/// Set the birthname in place. An empty `name` removes it.
+	pub fn set_birthname( &mut self, name: &str ) {
+		self.birthname = non_empty( name );
+	}
+
+	/// Set `title` as the only title in place. An empty `title` removes all titles.
+	pub fn set_title( &mut self, title: &str ) {
+		self.titles = non_empty( title ).into_iter().collect();
+	}
+
+	/// Set the titles in place. This replaces all titles already present.
+	pub fn set_titles( &mut self, titles: &[&str] ) {
+		self.titles = titles.iter().filter_map( |x| non_empty( x ) ).collect();
+	}
+
+	/// Set the rank in place. An empty `name` removes it.
+	pub fn set_rank( &mut self, name: &str ) {
+		self.rank = non_empty( name );
+	}
+
+	/// Set the key of the rank used for translating the rank in place. An empty `name` removes it.
+	pub fn set_rank_key( &mut self, name: &str ) {
+		self.rank_key = non_empty( name );
+	}
+
+	/// Set the nickname in place. An empty `name` removes it.
+	pub fn set_nickname( &mut self, name: &str ) {
+		self.nickname = non_empty( name );
+	}
+
+	/// Set the honorname in place. An empty `name` removes it.
+	pub fn set_honorname( &mut self, name: &str ) {
+		self.honorname = non_empty( name );
+	}
+
+	/// Set the supername in place. An empty `name` removes it.
+	pub fn set_supername( &mut self, name: &str ) {
+		self.supername = non_empty( name );
+	}
+
+	/// Set the regnal number in place. An empty `number` removes it.
+	pub fn set_regnal( &mut self, number: &str ) {
+		self.regnal = non_empty( number );
+	}
+
+	/// Set the gender in place. `None` removes the gender.
+	pub fn set_gender( &mut self, gender: Option<Gender> ) {
+		self.gender = gender;
+	}
+
+	/// Set in place whether the rank is feminized for women. See `with_rank_feminize()`.
+	pub fn set_rank_feminize( &mut self, feminize: bool ) {
+		self.rank_feminize = feminize;
+	}
+
+	/// Set in place whether the surname is feminized for women. See `with_surname_feminize()`.
+	pub fn set_surname_feminize( &mut self, feminize: bool ) {
+		self.surname_feminize = feminize;
+	}
+
+	/// Returns `true` if no name element is set at all. Settings that only affect how the name elements are expressed (the index of the Rufname and the feminization of rank and surname) are ignored.
+	pub fn is_empty( &self ) -> bool {
+		self.forenames.is_empty()
+			&& self.patronymic.is_none()
+			&& self.predicate.is_none()
+			&& self.surname.is_none()
+			&& self.surname_second.is_none()
+			&& self.suffix.is_none()
+			&& self.birthname.is_none()
+			&& self.titles.is_empty()
+			&& self.rank.is_none()
+			&& self.rank_key.is_none()
+			&& self.nickname.is_none()
+			&& self.honorname.is_none()
+			&& self.supername.is_none()
+			&& self.regnal.is_none()
+			&& self.gender.is_none()
+	}
+
+	/// Returns `true` if the name element `element` is set. The names of the elements are the same as used by `NameError::MissingNameElement`: "forenames", "patronymic", "predicate", "surname", "surname_second", "suffix", "birthname", "title", "rank", "nickname", "honorname", "supername", "regnal" and "gender". For unknown elements, this method returns `false`.
+	pub fn has_element( &self, element: &str ) -> bool {
+		match element {
+			"forenames" => !self.forenames.is_empty(),
+			"patronymic" => self.patronymic.is_some(),
+			"predicate" => self.predicate.is_some(),
+			"surname" => self.surname.is_some(),
+			"surname_second" => self.surname_second.is_some(),
+			"suffix" => self.suffix.is_some(),
+			"birthname" => self.birthname.is_some(),
+			"title" => !self.titles.is_empty(),
+			"rank" => self.rank.is_some(),
+			"nickname" => self.nickname.is_some(),
+			"honorname" => self.honorname.is_some(),
+			"supername" => self.supername.is_some(),
+			"regnal" => self.regnal.is_some(),
+			"gender" => self.gender.is_some(),
+			_ => false,
+		}
+	}
+
+	/// Returns `true` if all name elements required to express a name in the form of `form` are present. This method only checks for the presence of name elements. Even if this method returns `true`, `designate()` can still fail, Bsp. if the locale is not supported or the gender has no polite address.
+	pub fn can_designate( &self, form: NameCombo ) -> bool {
+		if form == NameCombo::Callsign {
+			return CALLSIGN_ELEMENTS.iter().any( |x| self.has_element( x ) );
+		}
+
+		form.required_elements().iter().all( |x| self.has_element( x ) )
+	}
+
+	/// Returns the names of all name elements required to express a name in the form of `form` that are missing in `self`. The names of the elements are the same as used by `NameError::MissingNameElement`. If nothing is missing, the returned vector is empty, but like with `can_designate()`, `designate()` can still fail for other reasons.
+	pub fn missing_for( &self, form: NameCombo ) -> Vec<&'static str> {
+		if form == NameCombo::Callsign {
+			return match self.can_designate( form ) {
+				true => Vec::new(),
+				false => CALLSIGN_ELEMENTS.to_vec(),
+			};
+		}
+
+		form.required_elements().iter()
+			.filter( |x| !self.has_element( x ) )
+			.copied()
+			.collect()
+	}
+
+	/// Combines `self` with `other`. For every name element, the value of `self` is kept if present, otherwise the value of `other` is used. The gender of `other` is also used if the gender of `self` is `Gender::Undefined`.
+	pub fn merge( self, other: Names ) -> Names {
+		let gender = match self.gender {
+			None | Some( Gender::Undefined ) => other.gender.or( self.gender ),
+			x => x,
+		};
+
+		// The index of the Rufname belongs to the forenames it has been set for.
+		let ( forenames, rufname_index ) = match self.forenames.is_empty() {
+			true => ( other.forenames, other.rufname_index ),
+			false => ( self.forenames, self.rufname_index ),
+		};
+
+		Self {
+			forenames,
+			rufname_index,
+			patronymic: self.patronymic.or( other.patronymic ),
+			predicate: self.predicate.or( other.predicate ),
+			surname: self.surname.or( other.surname ),
+			surname_second: self.surname_second.or( other.surname_second ),
+			suffix: self.suffix.or( other.suffix ),
+			birthname: self.birthname.or( other.birthname ),
+			titles: if self.titles.is_empty() { other.titles } else { self.titles },
+			rank: self.rank.or( other.rank ),
+			rank_key: self.rank_key.or( other.rank_key ),
+			nickname: self.nickname.or( other.nickname ),
+			honorname: self.honorname.or( other.honorname ),
+			supername: self.supername.or( other.supername ),
+			regnal: self.regnal.or( other.regnal ),
+			gender,
+			rank_feminize: self.rank_feminize || other.rank_feminize,
+			surname_feminize: self.surname_feminize || other.surname_feminize,
+		}
+	}
+
 	/// Return the `Gender`.
 	pub fn gender( &self ) -> &Option<Gender> {
 		&self.gender
 	}
 
+	/// Returns `true` if the rank is feminized for women.
+	pub fn rank_feminize( &self ) -> bool {
+		self.rank_feminize
+	}
+
+	/// Returns `true` if the surname is feminized for women.
+	pub fn surname_feminize( &self ) -> bool {
+		self.surname_feminize
+	}
+
 	/// Returns all forenames.
 	pub fn forenames( &self ) -> &Vec<String> {
 		&self.forenames
 	}
 
+	/// Returns an iterator over all forenames, each paired with its initial. Bsp. "Penelope Karin" => ("Penelope", "P."), ("Karin", "K.")
+	pub fn forename_initials( &self ) -> impl Iterator<Item = ( &str, String )> {
+		self.forenames.iter().map( |x| ( x.as_str(), initials( x ) ) )
+	}
+
+	/// Returns the patronymic.
+	pub fn patronymic( &self ) -> Option<&str> {
+		self.patronymic.as_deref()
+	}
+
+	/// Returns the predicate of the surname. Bsp. "von".
+	pub fn predicate( &self ) -> Option<&str> {
+		self.predicate.as_deref()
+	}
+
+	/// Returns the surname without the predicate. Bsp. "Würzinger".
+	pub fn surname( &self ) -> Option<&str> {
+		self.surname.as_deref()
+	}
+
+	/// Returns the second surname.
+	pub fn second_surname( &self ) -> Option<&str> {
+		self.surname_second.as_deref()
+	}
+
+	/// Returns the generational suffix. Bsp. "Jr.".
+	pub fn suffix( &self ) -> Option<&str> {
+		self.suffix.as_deref()
+	}
+
+	/// Returns the birthname.
+	pub fn birthname( &self ) -> Option<&str> {
+		self.birthname.as_deref()
+	}
+
+	/// Returns the first title. Bsp. "Prof." if the titles are "Prof." and "Dr.". Use `titles()` to get all titles.
+	pub fn title( &self ) -> Option<&str> {
+		self.titles.first().map( |x| x.as_str() )
+	}
+
+	/// Returns all titles.
+	pub fn titles( &self ) -> &Vec<String> {
+		&self.titles
+	}
+
+	/// Returns the rank as it has been set. This is not translated, even if a rank key is set.
+	pub fn rank( &self ) -> Option<&str> {
+		self.rank.as_deref()
+	}
+
+	/// Returns the key of the rank used for translating the rank.
+	pub fn rank_key( &self ) -> Option<&str> {
+		self.rank_key.as_deref()
+	}
+
+	/// Returns the nickname.
+	pub fn nickname( &self ) -> Option<&str> {
+		self.nickname.as_deref()
+	}
+
+	/// Returns the honorname.
+	pub fn honorname( &self ) -> Option<&str> {
+		self.honorname.as_deref()
+	}
+
+	/// Returns the supername.
+	pub fn supername( &self ) -> Option<&str> {
+		self.supername.as_deref()
+	}
+
+	/// Returns the regnal number.
+	pub fn regnal( &self ) -> Option<&str> {
+		self.regnal.as_deref()
+	}
+
 	/// Returns all forenames as a string. Bsp. "Thomas Jakob". If no forename is given, this returns `None`.
 	fn forenames_string( &self ) -> Result<String, NameError> {
 		if self.forenames.is_empty() {
@@ -450,6 +1885,19 @@ impl Names {
 		Ok( self.forenames.join( " " ) )
 	}
 
+	/// Returns all titles as a string. Bsp. "Prof. Dr.". If no title is given, this returns `None`.
+	fn titles_string( &self ) -> Option<String> {
+		if self.titles.is_empty() {
+			return None;
+		}
+		Some( self.titles.join( " " ) )
+	}
+
+	/// Returns all titles as a string. Bsp. "Prof. Dr.". If no title is given, this returns an error.
+	fn titles_res( &self ) -> Result<String, NameError> {
+		self.titles_string().ok_or( NameError::MissingNameElement( "title".to_string() ) )
+	}
+
 	/// Returns the first forename. If no forenames are given, this method returns `None`.
 	pub fn firstname( &self ) -> Option<&str> {
 		self.forenames.first().map( |x| x.as_str() )
@@ -460,193 +1908,521 @@ impl Names {
 		self.forenames.first().map( |x| x.as_str() ).ok_or( NameError::MissingNameElement( "forenames".to_string() ) )
 	}
 
-	/// Returns the full surname including all predicates. Bsp. "von Würzinger".
-	pub fn surname_full( &self ) -> Option<String> {
-		let res = match &self.predicate {
-			Some( x ) => format!( "{} {}", x, &self.surname.as_ref()? ),
-			None => self.surname.clone()?,
+	/// Returns the index of the Rufname within the forenames, if set. See `with_rufname_index()`.
+	pub fn rufname_index( &self ) -> Option<usize> {
+		self.rufname_index
+	}
+
+	/// Returns the Rufname, the forename a person is actually called by. If no index of the Rufname is set, this is the first forename. If no forename is given, this returns an error. If the index of the Rufname is out of range, this returns `NameError::NotExpressionable`.
+	fn rufname_res( &self ) -> Result<&str, NameError> {
+		let Some( index ) = self.rufname_index else {
+			return self.firstname_res();
+		};
+
+		if self.forenames.is_empty() {
+			return Err( NameError::MissingNameElement( "forenames".to_string() ) );
+		}
+
+		self.forenames.get( index )
+			.map( |x| x.as_str() )
+			.ok_or_else( || NameError::NotExpressionable(
+				format!( "Rufname index {} out of range for {} forenames", index, self.forenames.len() )
+			) )
+	}
+
+	/// Returns the initials of the predicate according to `style`. With `InitialsStyle::Full`, a predicate consisting of a single word is abbreviated (Bsp. "von" => "v."), while a predicate consisting of multiple words is kept as it is (Bsp. "de la"). If no predicate is given or it is dropped by `style`, this returns `None`.
+	fn predicate_initials( &self, style: InitialsStyle ) -> Option<String> {
+		let predicate = self.predicate.as_ref()?;
+		match style {
+			InitialsStyle::DropParticles => None,
+			InitialsStyle::KeepParticles => Some( predicate.clone() ),
+			InitialsStyle::Full if predicate.contains( ' ' ) => Some( predicate.clone() ),
+			InitialsStyle::Full => Some( particle_initials( predicate ) ),
+		}
+	}
+
+	/// Returns the rank. If the **`i18n`** feature is enabled and a rank key is set, the rank is translated into the language of `locale`. If no rank is available, this returns an error.
+	fn rank_res( &self, locale: &LanguageIdentifier ) -> Result<Cow<'_, str>, NameError> {
+		#[cfg( feature = "i18n" )]
+		if let Some( x ) = self.rank_key.as_ref().and_then( |x| LOCALES.try_lookup( locale, x ) ) {
+			return Ok( self.rank_feminized( Cow::Owned( x ), locale ) );
+		}
+
+		self.rank.as_deref()
+			.map( |x| self.rank_feminized( Cow::Borrowed( x ), locale ) )
+			.ok_or( NameError::MissingNameElement( "rank".to_string() ) )
+	}
+
+	/// Returns `rank` with the German feminine suffix "-in" if feminization is enabled for `self` and the gender is `Gender::Female`. Bsp. "Major" => "Majorin". A rank already ending in "in" and ranks in other languages are returned unchanged.
+	fn rank_feminized<'a>( &self, rank: Cow<'a, str>, locale: &LanguageIdentifier ) -> Cow<'a, str> {
+		if !self.rank_feminize || self.gender != Some( Gender::Female ) || locale.language.as_str() != "de" || rank.ends_with( "in" ) {
+			return rank;
+		}
+
+		Cow::Owned( format!( "{}in", rank ) )
+	}
+
+	/// Returns `surname` in the Czech or Slovak feminine form if feminization is enabled for `self` and the gender is `Gender::Female`. Adjectival surnames change their ending "-ý" into "-á" (Bsp. "Novotný" => "Novotná", "Dvorský" => "Dvorská"), all other surnames get the suffix "-ová", replacing a final "-a" or "-ek" (Bsp. "Novák" => "Nováková", "Svoboda" => "Svobodová", "Hájek" => "Hájková"). Surnames already ending in "-á" and surnames in other languages are returned unchanged.
+	fn surname_feminized<'a>( &self, surname: &'a str, locale: &LanguageIdentifier ) -> Cow<'a, str> {
+		if !self.surname_feminize || self.gender != Some( Gender::Female ) || !matches!( locale.language.as_str(), "cs" | "sk" ) || surname.ends_with( 'á' ) {
+			return Cow::Borrowed( surname );
+		}
+
+		if let Some( stem ) = surname.strip_suffix( 'ý' ) {
+			return Cow::Owned( format!( "{}á", stem ) );
+		}
+
+		let stem = match surname.strip_suffix( "ek" ) {
+			Some( x ) => format!( "{}k", x ),
+			None => surname.strip_suffix( 'a' ).unwrap_or( surname ).to_string(),
 		};
 
-		Some( res )
+		Cow::Owned( format!( "{}ová", stem ) )
 	}
 
-	/// Returns the full surname including all predicates. Bsp. "von Würzinger".
-	fn surname_full_res( &self ) -> Result<String, NameError> {
+	/// Returns the surname followed by the second surname (if present) without any predicates. Bsp. "García Márquez". The surname is feminized for `locale` if enabled (see `with_surname_feminize()`). If no surname is given, this returns an error.
+	fn surnames_res( &self, locale: &LanguageIdentifier ) -> Result<String, NameError> {
 		let surname = self.surname.as_ref().ok_or( NameError::MissingNameElement( "surname".to_string() ) )?;
+		let surname = self.surname_feminized( surname, locale );
+		let res = match &self.surname_second {
+			Some( x ) => format!( "{} {}", surname, x ),
+			None => surname.into_owned(),
+		};
+
+		Ok( res )
+	}
+
+	/// Returns the full surname including all predicates and the second surname. Bsp. "von Würzinger". If no surname is set, this returns `None`, even if a predicate is set.
+	pub fn surname_full( &self ) -> Option<String> {
+		self.surname_full_res( &DEFAULT_LOCALE ).ok()
+	}
+
+	/// Returns the full surname including all predicates and the second surname. Bsp. "von Würzinger". The surname is feminized for `locale` if enabled (see `with_surname_feminize()`).
+	fn surname_full_res( &self, locale: &LanguageIdentifier ) -> Result<String, NameError> {
+		let surname = self.surnames_res( locale )?;
 		let res = match &self.predicate {
 			Some( x ) => format!( "{} {}", x, surname ),
-			None => surname.clone(),
+			None => surname,
 		};
 
 		Ok( res )
 	}
 
-	/// This method returns how a persone with the name elements in `self` can be called according to the chose `form` in a specific language (`locale`). If `self` cannot be expressed with `form` (maybe a relevant name part is missing), this method returns an error.
+	/// Returns the full surname including all predicates for usage without a preceding forename. In Dutch, the first letter of the predicate is capitalized in this case (Bsp. "meneer Van der Berg" but "Jan van der Berg"). In all other languages, this is identical to `surname_full_res()`.
+	fn surname_standalone_res( &self, locale: &LanguageIdentifier ) -> Result<String, NameError> {
+		let res = self.surname_full_res( locale )?;
+
+		if locale.language.as_str() != "nl" || self.predicate.is_none() {
+			return Ok( res );
+		}
+
+		Ok( capitalize( &res ) )
+	}
+
+	/// Returns the article preceding the honorific name in lowercase. In English, this is always "the". In German, the article depends on the gender. For genders other than male and neutral (including an unknown gender), "die" is used, since a person ("die Person") is grammatically feminine. An honorific name starting with a preposition (see `HONOR_PREPOSITIONS`, Bsp. "of Aquitaine") is not preceded by an article, so this returns `None` in this case.
+	///
+	/// # Error
+	/// If the `locale` is not supported, this method returns an error.
+	fn honor_article( &self, locale: &LanguageIdentifier ) -> Result<Option<&'static str>, NameError> {
+		let res = match locale.language.as_str() {
+			"en" => "the",
+			"de" => match self.gender {
+				Some( Gender::Male ) => "der",
+				Some( Gender::Neutral ) => "das",
+				_ => "die",
+			},
+			_ => return Err( NameError::LangNotSupported( locale.to_string() ) ),
+		};
+
+		let prepositional = self.honorname.as_deref()
+			.and_then( |x| x.split_whitespace().next() )
+			.is_some_and( |x| HONOR_PREPOSITIONS.contains( &x ) );
+		if prepositional {
+			return Ok( None );
+		}
+
+		Ok( Some( res ) )
+	}
+
+	/// Returns the name with the surname first (Bsp. "Würzinger, Penelope von"), placing the predicate according to `style`. If `full` is `true`, all forenames are used and the birthname follows the surname (Bsp. "Würzinger geb. Stauff, Penelope Karin von"). Only the surname is inflected according to `case`, since it is the first part of the name. Bsp. "Würzingers, Penelope von" in the German genetive.
 	///
 	/// # Arguments
-	/// * `form` The name combination.
+	/// * `style` the position of the predicate.
+	/// * `full` whether to use all forenames and the birthname.
 	/// * `case` the grammatical case.
-	/// * `locale` the locale to use the grammatical rules of. Currently only English and German are supported.
-	///
-	/// # Returns
-	/// Returns the calling of the name.
-	pub fn designate( &self, form: NameCombo, case: GrammaticalCase, locale: &LanguageIdentifier ) -> Result<String, NameError> {
-		match form {
-			NameCombo::Name => {
-				if self.forenames.is_empty() {
-					return Err( NameError::MissingNameElement( "forenames".to_string() ) );
-				}
-				let res = add_case_letter(
-					&format!( "{} {}", self.forenames[0], self.surname_full_res()? ),
+	/// * `locale` the locale to use the grammatical rules and the birthname marker of.
+	fn ordered_name_res( &self, style: OrderStyle, full: bool, case: GrammaticalCase, locale: &LanguageIdentifier ) -> Result<String, NameError> {
+		let ( surname, predicate ) = match style {
+			OrderStyle::PredicateAfterForename => ( self.surnames_res( locale )?, self.predicate.as_deref() ),
+			OrderStyle::PredicateBeforeSurname => ( self.surname_full_res( locale )?, None ),
+		};
+		let surname = add_case_letter_gendered( surname, case, locale, self.gender )?.into_owned();
+		let ( surname, forenames ) = match ( full, &self.birthname ) {
+			( true, Some( x ) ) => ( format!( "{} {} {}", surname, birthname_marker( locale, self.gender )?, x ), self.forenames_string().ok() ),
+			( true, None ) => ( surname, self.forenames_string().ok() ),
+			( false, _ ) => ( surname, self.firstname().map( ToString::to_string ) ),
+		};
+		let names = [
+			forenames.as_deref(),
+			predicate,
+		];
+		let res = format!( "{}, {}",
+			surname,
+			names.iter()
+				.filter_map( |&x| x )
+				.collect::<Vec<&str>>()
+				.join( " " )
+		);
+		let res = match &self.suffix {
+			Some( x ) => format!( "{}, {}", res, x ),
+			None => res,
+		};
+
+		Ok( res )
+	}
+
+	/// Returns all titles in the grammatical `case`. Bsp. "Doktor" => "Doktors" in the German genetive. If no title is given, this returns an error.
+	///
+	/// # Arguments
+	/// * `case` the grammatical case.
+	/// * `locale` the locale to use the grammatical rules of.
+	fn titles_case_res( &self, case: GrammaticalCase, locale: &LanguageIdentifier ) -> Result<String, NameError> {
+		let titles = self.titles.iter()
+			.map( |x| inflect_title( x.as_str(), case, locale, self.gender ) )
+			.collect::<Result<Vec<Cow<str>>, NameError>>()?;
+
+		if titles.is_empty() {
+			return Err( NameError::MissingNameElement( "title".to_string() ) );
+		}
+
+		Ok( titles.join( " " ) )
+	}
+
+	/// Returns the rank in the grammatical `case`. Bsp. "Hauptmann" => "Hauptmanns" in the German genetive. If no rank is given, this returns an error.
+	///
+	/// # Arguments
+	/// * `case` the grammatical case.
+	/// * `locale` the locale to use the grammatical rules of.
+	fn rank_case_res( &self, case: GrammaticalCase, locale: &LanguageIdentifier ) -> Result<Cow<'_, str>, NameError> {
+		inflect_title( self.rank_res( locale )?, case, locale, self.gender )
+	}
+
+	/// Returns the first forename followed by the nickname, which is delimited according to `style`. Bsp. "Thomas (Würzi)".
+	///
+	/// A bare nickname is used like a surname and takes the case letter (Bsp. genetive "Thomas Würzis"). A delimited nickname is only an addition to the forename, so the forename takes the case letter instead (Bsp. genetive "Thomas' (Würzi)").
+	///
+	/// # Arguments
+	/// * `style` the delimiters of the nickname.
+	/// * `case` the grammatical case.
+	/// * `locale` the locale to use the grammatical rules of.
+	fn first_nickname_res( &self, style: NicknameStyle, case: GrammaticalCase, locale: &LanguageIdentifier ) -> Result<String, NameError> {
+		let nick = self.nickname.as_ref().ok_or( NameError::MissingNameElement( "nickname".to_string() ) )?;
+		if style == NicknameStyle::Bare {
+			let name = self.designate( NameCombo::Firstname, GrammaticalCase::Nominative, locale )?;
+			return add_case_letter( format!( "{} {}", name, nick ), case, locale ).map( Cow::into_owned );
+		}
+
+		let name = self.designate( NameCombo::Firstname, case, locale )?;
+		Ok( format!( "{} {}", name, nickname_styled( nick, style ) ) )
+	}
+
+	/// Combines `forenames` with the full surname in the order customary for `locale`. Bsp. "Penelope von Würzinger" in German, but "Nagy Péter" in Hungarian (see `SURNAME_FIRST_LANGUAGES`).
+	///
+	/// # Arguments
+	/// * `forenames` the forenames to combine with the surname.
+	/// * `locale` the locale defining the order.
+	fn name_ordered_res( &self, forenames: &str, locale: &LanguageIdentifier ) -> Result<String, NameError> {
+		let surname = self.surname_full_res( locale )?;
+
+		if SURNAME_FIRST_LANGUAGES.contains( &locale.language.as_str() ) {
+			return Ok( format!( "{} {}", surname, forenames ) );
+		}
+
+		Ok( format!( "{} {}", forenames, surname ) )
+	}
+
+	/// Appends the suffix (if present) to `text` and adds the letters depending on the grammatical case. In German, the case letter is added to `text` before the suffix (Bsp. "Kennedys Jr."), otherwise the case letter is added after the suffix (Bsp. "Kennedy Jr.'s").
+	///
+	/// # Arguments
+	/// * `text` the name the suffix is appended to.
+	/// * `case` the grammatical case.
+	/// * `locale` the locale to use the grammatical rules of.
+	fn add_suffix_case_letter( &self, text: String, case: GrammaticalCase, locale: &LanguageIdentifier ) -> Result<String, NameError> {
+		let Some( suffix ) = &self.suffix else {
+			return add_case_letter_gendered( text, case, locale, self.gender ).map( Cow::into_owned );
+		};
+
+		match locale.language.as_str() {
+			"de" => Ok( format!( "{} {}", add_case_letter_gendered( text, case, locale, self.gender )?, suffix ) ),
+			_ => add_case_letter_gendered( format!( "{} {}", text, suffix ), case, locale, self.gender ).map( Cow::into_owned ),
+		}
+	}
+
+	/// Works like [`Names::designate`] but avoids allocating a new string if the designation is an unmodified name element of `self` (Bsp. `NameCombo::Firstname` in the nominative case).
+	///
+	/// # Arguments
+	/// * `form` The name combination.
+	/// * `case` the grammatical case.
+	/// * `locale` the locale to use the grammatical rules of.
+	///
+	/// # Returns
+	/// Returns the calling of the name.
+	pub fn designate_cow( &self, form: NameCombo, case: GrammaticalCase, locale: &LanguageIdentifier ) -> Result<Cow<'_, str>, NameError> {
+		self.designate_opts_cow( form, &DesignateOptions::new( case, locale ) )
+	}
+
+	/// Works like [`Names::designate`] but takes the grammatical case, the locale and the styles of some name combinations from `opts`. Bsp. `NameCombo::Initials` with `InitialsStyle::KeepParticles` results in "T. von W.".
+	///
+	/// # Arguments
+	/// * `form` The name combination.
+	/// * `opts` The options of the designation.
+	pub fn designate_opts( &self, form: NameCombo, opts: &DesignateOptions ) -> Result<String, NameError> {
+		self.designate_opts_cow( form, opts ).map( Cow::into_owned )
+	}
+
+	/// Works like [`Names::designate_opts`] but avoids allocating a new string if possible (see [`Names::designate_cow`]).
+	fn designate_opts_cow( &self, form: NameCombo, opts: &DesignateOptions ) -> Result<Cow<'_, str>, NameError> {
+		// Raw designations use the name elements as they are stored, so they are composed without feminization.
+		if opts.raw && ( self.rank_feminize || self.surname_feminize ) {
+			let verbatim = Self {
+				rank_feminize: false,
+				surname_feminize: false,
+				..self.clone()
+			};
+			return verbatim.designate_opts_cow( form, opts ).map( |x| Cow::Owned( x.into_owned() ) );
+		}
+
+		self.compose( form, opts )
+			.map( collapse_whitespace )
+			.map_err( |e| match e {
+				NameError::MissingNameElement( missing ) | NameError::CannotExpress { missing, .. } => NameError::CannotExpress { combo: form, missing },
+				e => e,
+			} )
+	}
+
+	/// Works like [`Names::designate_opts`] but uses the grammatical `case` instead of the one of `opts`. This is used to compose a name combination from other name combinations while keeping the styles of `opts`.
+	fn designate_with( &self, form: NameCombo, case: GrammaticalCase, opts: &DesignateOptions ) -> Result<String, NameError> {
+		self.designate_opts( form, &DesignateOptions { case, ..opts.clone() } )
+	}
+
+	/// Combines the name elements according to `form`. The result may contain superfluous whitespace, which is removed by [`Names::designate_cow`].
+	///
+	/// # Arguments
+	/// * `form` The name combination.
+	/// * `opts` The options of the designation.
+	fn compose( &self, form: NameCombo, opts: &DesignateOptions ) -> Result<Cow<'_, str>, NameError> {
+		// Raw designations are composed in the nominative, which leaves every name element uninflected.
+		let case = if opts.raw { GrammaticalCase::Nominative } else { opts.case };
+		let locale = &opts.locale;
+
+		match form {
+			NameCombo::Name => {
+				let res = self.add_suffix_case_letter(
+					self.name_ordered_res( self.rufname_res()?, locale )?,
 					case,
 					locale
 				)?;
-				Ok( res )
+				Ok( res.into() )
 			},
-			NameCombo::Surname => add_case_letter(
-				&self.surname_full_res()?,
+			NameCombo::Surname => add_case_letter_gendered(
+				if opts.raw { self.surname_full_res( locale )? } else { self.surname_standalone_res( locale )? },
 				case,
-				locale
+				locale,
+				self.gender
 			),
 			NameCombo::Firstname => add_case_letter(
-				self.firstname_res()?,
+				self.rufname_res()?,
 				case,
 				locale
 			),
 			NameCombo::Forenames => add_case_letter(
-				&self.forenames_string()?,
+				self.forenames_string()?,
 				case,
 				locale
 			),
 			NameCombo::Fullname => {
-				let name = add_case_letter(
-					&format!( "{} {}", self.forenames_string()?, self.surname_full_res()? ),
+				let name = self.add_suffix_case_letter(
+					self.name_ordered_res( &self.forenames_string()?, locale )?,
 					case,
 					locale
 				)?;
+				// The birthname following the marker is never inflected.
 				let res = match &self.birthname {
-					Some( x ) => format!( "{} geb. {}", name, x ),
+					Some( x ) => format!( "{} {} {}", name, birthname_marker( locale, self.gender )?, x ),
 					None => name,
 				};
-				Ok( res )
+				Ok( res.into() )
 			},
-			NameCombo::Title => self.title.clone().ok_or( NameError::MissingNameElement( "title".to_string() ) ),
+			NameCombo::Title => self.titles_case_res( case, locale ).map( Cow::Owned ),
 			NameCombo::TitleName => {
-				let title = self.title.as_ref().ok_or( NameError::MissingNameElement( "title".to_string() ) )?;
-				let name = self.designate( NameCombo::Name, case, locale )?;
-				Ok( format!( "{} {}", title, name ) )
+				let title = self.titles_res()?;
+				let name = self.designate_with( NameCombo::Name, case, opts )?;
+				Ok( format!( "{} {}", title, name ).into() )
 			},
 			NameCombo::TitleFirstname => {
-				let title = self.title.as_ref().ok_or( NameError::MissingNameElement( "title".to_string() ) )?;
-				let name = self.designate( NameCombo::Firstname, case, locale )?;
-				Ok( format!( "{} {}", title, name ) )
+				let title = self.titles_res()?;
+				let name = self.designate_with( NameCombo::Firstname, case, opts )?;
+				Ok( format!( "{} {}", title, name ).into() )
 			},
 			NameCombo::TitleSurname => {
-				let title = self.title.as_ref().ok_or( NameError::MissingNameElement( "title".to_string() ) )?;
-				Ok( format!( "{} {}", title, self.designate( NameCombo::Surname, case, locale ).unwrap() ) )
+				let title = self.titles_res()?;
+				let name = self.designate_with( NameCombo::Surname, case, opts )?;
+				Ok( format!( "{} {}", title, name ).into() )
 			},
 			NameCombo::TitleFullname => {
-				let title = self.title.as_ref().ok_or( NameError::MissingNameElement( "title".to_string() ) )?;
-				let name = self.designate( NameCombo::Fullname, case, locale )?;
-				Ok( format!( "{} {}", title, name ) )
+				let title = self.titles_res()?;
+				let name = self.designate_with( NameCombo::Fullname, case, opts )?;
+				Ok( format!( "{} {}", title, name ).into() )
 			},
 			NameCombo::Polite => self.gender
 				.ok_or( NameError::MissingNameElement( "gender".to_string() ) )?
-				.polite( locale ),
+				.polite( locale )
+				.map( Cow::Owned ),
 			NameCombo::PoliteName => {
 				let polite = self.gender
 					.ok_or( NameError::MissingNameElement( "gender".to_string() ) )?
 					.polite( locale )?;
-				let name = self.designate( NameCombo::Name, case, locale )?;
-				Ok( format!( "{} {}", polite, name ) )
+				let name = self.designate_with( NameCombo::Name, case, opts )?;
+				Ok( format!( "{} {}", polite, name ).into() )
 			},
 			NameCombo::PoliteFirstname => {
 				let polite = self.gender
 					.ok_or( NameError::MissingNameElement( "gender".to_string() ) )?
 					.polite( locale )?;
-				let name = self.designate( NameCombo::Firstname, case, locale )?;
-				Ok( format!( "{} {}", polite, name ) )
+				let name = self.designate_with( NameCombo::Firstname, case, opts )?;
+				Ok( format!( "{} {}", polite, name ).into() )
 			},
 			NameCombo::PoliteSurname => {
 				let polite = self.gender
 					.ok_or( NameError::MissingNameElement( "gender".to_string() ) )?
 					.polite( locale )?;
-				Ok( format!( "{} {}", polite, self.designate( NameCombo::Surname, case, locale ).unwrap() ) )
+				let name = self.designate_with( NameCombo::Surname, case, opts )?;
+				Ok( format!( "{} {}", polite, name ).into() )
 			},
 			NameCombo::PoliteFullname => {
 				let polite = self.gender
 					.ok_or( NameError::MissingNameElement( "gender".to_string() ) )?
 					.polite( locale )?;
-				let name = self.designate( NameCombo::Fullname, case, locale )?;
-				Ok( format!( "{} {}", polite, name ) )
+				let name = self.designate_with( NameCombo::Fullname, case, opts )?;
+				Ok( format!( "{} {}", polite, name ).into() )
 			},
 			NameCombo::PoliteTitleName => {
 				let polite = self.gender
 					.ok_or( NameError::MissingNameElement( "gender".to_string() ) )?
 					.polite( locale )?;
-				let title = self.title.as_ref()
-					.ok_or( NameError::MissingNameElement( "title".to_string() ) )?;
-				let name = self.designate( NameCombo::Name, case, locale )?;
-				Ok( format!( "{} {} {}", polite, title, name ) )
+				let title = self.titles_res()?;
+				let name = self.designate_with( NameCombo::Name, case, opts )?;
+				Ok( format!( "{} {} {}", polite, title, name ).into() )
+			},
+			NameCombo::PoliteTitleSurname => {
+				let polite = self.gender
+					.ok_or( NameError::MissingNameElement( "gender".to_string() ) )?
+					.polite( locale )?;
+				let title = self.titles_res()?;
+				let name = self.designate_with( NameCombo::Surname, case, opts )?;
+				Ok( format!( "{} {} {}", polite, title, name ).into() )
+			},
+			NameCombo::PoliteTitleFullname => {
+				let polite = self.gender
+					.ok_or( NameError::MissingNameElement( "gender".to_string() ) )?
+					.polite( locale )?;
+				let title = self.titles_res()?;
+				let name = self.designate_with( NameCombo::Fullname, case, opts )?;
+				Ok( format!( "{} {} {}", polite, title, name ).into() )
 			},
-			NameCombo::Rank => self.rank.clone()
-				.ok_or( NameError::MissingNameElement( "title".to_string() ) ),
+			NameCombo::Rank => self.rank_case_res( case, locale ),
 			NameCombo::RankName => {
-				let rank = self.rank.as_ref().ok_or( NameError::MissingNameElement( "rank".to_string() ) )?;
-				let name = self.designate( NameCombo::Name, case, locale )?;
-				Ok( format!( "{} {}", rank, name ) )
+				let rank = self.rank_res( locale )?;
+				let name = self.designate_with( NameCombo::Name, case, opts )?;
+				Ok( format!( "{} {}", rank, name ).into() )
 			},
 			NameCombo::PoliteRank => {
 				let polite = self.gender
 					.ok_or( NameError::MissingNameElement( "gender".to_string() ) )?
 					.polite( locale )?;
-				let rank = self.rank.as_ref().ok_or( NameError::MissingNameElement( "rank".to_string() ) )?;
-				Ok( format!( "{} {}", polite, rank ) )
+				let rank = self.rank_res( locale )?;
+				Ok( format!( "{} {}", polite, rank ).into() )
+			},
+			NameCombo::PoliteRankSurname => {
+				let polite = self.gender
+					.ok_or( NameError::MissingNameElement( "gender".to_string() ) )?
+					.polite( locale )?;
+				let rank = self.rank_res( locale )?;
+				let name = self.designate_with( NameCombo::Surname, case, opts )?;
+				Ok( format!( "{} {} {}", polite, rank, name ).into() )
 			},
 			NameCombo::RankFirstname => {
-				let rank = self.rank.as_ref().ok_or( NameError::MissingNameElement( "rank".to_string() ) )?;
-				let name = self.designate( NameCombo::Firstname, case, locale )?;
-				Ok( format!( "{} {}", rank, name ) )
+				let rank = self.rank_res( locale )?;
+				let name = self.designate_with( NameCombo::Firstname, case, opts )?;
+				Ok( format!( "{} {}", rank, name ).into() )
 			},
 			NameCombo::RankSurname => {
-				let rank = self.rank.as_ref().ok_or( NameError::MissingNameElement( "rank".to_string() ) )?;
-				Ok( format!( "{} {}", rank, self.designate( NameCombo::Surname, case, locale ).unwrap() ) )
+				let rank = self.rank_res( locale )?;
+				let name = self.designate_with( NameCombo::Surname, case, opts )?;
+				Ok( format!( "{} {}", rank, name ).into() )
 			},
 			NameCombo::RankFullname => {
-				let rank = self.rank.as_ref().ok_or( NameError::MissingNameElement( "rank".to_string() ) )?;
-				let name = self.designate( NameCombo::Fullname, case, locale )?;
-				Ok( format!( "{} {}", rank, name ) )
+				let rank = self.rank_res( locale )?;
+				let name = self.designate_with( NameCombo::Fullname, case, opts )?;
+				Ok( format!( "{} {}", rank, name ).into() )
 			},
 			NameCombo::RankTitleName => {
-				let rank = self.rank.as_ref().ok_or( NameError::MissingNameElement( "rank".to_string() ) )?;
-				let title = self.title.as_ref().ok_or( NameError::MissingNameElement( "title".to_string() ) )?;
-				let name = self.designate( NameCombo::Name, case, locale )?;
-				Ok( format!( "{} {} {}", rank, title, name ) )
+				let rank = self.rank_res( locale )?;
+				let title = self.titles_res()?;
+				let name = self.designate_with( NameCombo::Name, case, opts )?;
+				Ok( format!( "{} {} {}", rank, title, name ).into() )
+			},
+			NameCombo::TitleRankName => {
+				let title = self.titles_res()?;
+				let rank = self.rank_res( locale )?;
+				let name = self.designate_with( NameCombo::Name, case, opts )?;
+				Ok( format!( "{} {} {}", title, rank, name ).into() )
 			},
 			NameCombo::Nickname => add_case_letter(
 				self.nickname.as_ref().ok_or( NameError::MissingNameElement( "nickname".to_string() ) )?,
 				case,
 				locale
 			),
-			NameCombo::FirstNickname => {
-				let name = self.designate( NameCombo::Firstname, case, locale )?;
+			NameCombo::FirstNickname => self.first_nickname_res( opts.nickname_style, case, locale ).map( Cow::Owned ),
+			NameCombo::FirstNicknameQuoted => self.first_nickname_res( NicknameStyle::DoubleQuoted, case, locale ).map( Cow::Owned ),
+			NameCombo::FirstNicknameParenthesized => self.first_nickname_res( NicknameStyle::Parenthesized, case, locale ).map( Cow::Owned ),
+			NameCombo::NickSurname => {
 				let nick = self.nickname.as_ref().ok_or( NameError::MissingNameElement( "nickname".to_string() ) )?;
-				Ok( format!( "{} {}", name, nick ) )
+				Ok( format!( "{} {}", nick, self.designate_with( NameCombo::Surname, case, opts )? ).into() )
 			},
-			NameCombo::NickSurname => {
+			NameCombo::SurnameNickname => {
 				let nick = self.nickname.as_ref().ok_or( NameError::MissingNameElement( "nickname".to_string() ) )?;
-				Ok( format!( "{} {}", nick, self.designate( NameCombo::Surname, case, locale )? ) )
+				let surname = self.designate_with( NameCombo::Surname, case, opts )?;
+				Ok( format!( "{} '{}'", surname, nick ).into() )
 			},
 			NameCombo::DuaNomina => {
 				let nick = self.nickname.as_ref().ok_or( NameError::MissingNameElement( "nickname".to_string() ) )?;
 				let surname = self.surname.as_ref().ok_or( NameError::MissingNameElement( "surname".to_string() ) )?;
-				add_case_letter( &format!( "{} {}", surname, nick ), case, locale )
+				add_case_letter( format!( "{} {}", surname, nick ), case, locale )
 			},
 			NameCombo::TriaNomina => {
-				let name = self.designate( NameCombo::Firstname, case, locale )?;
+				let name = self.designate_with( NameCombo::Firstname, case, opts )?;
 				let nick = self.nickname.as_ref().ok_or( NameError::MissingNameElement( "nickname".to_string() ) )?;
 				let surname = self.surname.as_ref().ok_or( NameError::MissingNameElement( "surname".to_string() ) )?;
-				add_case_letter( &format!( "{} {} {}", name, surname, nick ), case, locale )
+				add_case_letter( format!( "{} {} {}", name, surname, nick ), case, locale )
+			},
+			NameCombo::NamePatronymic => {
+				let patronymic = self.patronymic.as_ref().ok_or( NameError::MissingNameElement( "patronymic".to_string() ) )?;
+				add_case_letter_gendered(
+					format!( "{} {} {}", self.firstname_res()?, patronymic, self.surname_full_res( locale )? ),
+					case,
+					locale,
+					self.gender
+				)
+			},
+			NameCombo::Nasab => {
+				let patronymic = self.patronymic.as_ref().ok_or( NameError::MissingNameElement( "patronymic".to_string() ) )?;
+				let connector = nasab_connector( self.gender )?;
+				add_case_letter(
+					format!( "{} {} {} {}", self.firstname_res()?, connector, patronymic, self.surname_full_res( locale )? ),
+					case,
+					locale
+				)
 			},
 			NameCombo::Honor => add_case_letter(
 				self.honorname.as_ref().ok_or( NameError::MissingNameElement( "honorname".to_string() ) )?,
@@ -654,53 +2430,73 @@ impl Names {
 				locale
 			),
 			NameCombo::Honortitle => {
-				let honor = self.designate( NameCombo::Honor, case, locale )?;
-				let res = match self.gender {
-					Some( Gender::Female ) => format!( "Die {}", honor ),
-					Some( Gender::Male ) => format!( "Der {}", honor ),
-					Some( Gender::Neutral ) => format!( "Das {}", honor ),
-					_ => honor.to_string(),
+				let honor = self.designate_with( NameCombo::Honor, case, opts )?;
+				let res = match self.honor_article( locale )? {
+					Some( x ) => format!( "{} {}", capitalize( x ), honor ),
+					None => honor,
 				};
-				Ok( res )
+				Ok( res.into() )
 			},
 			NameCombo::FirstHonorname => {
-				let name = self.designate( NameCombo::Firstname, case, locale )?;
-				let honor = self.designate( NameCombo::Honor, case, locale )?;
-				let res = match self.gender {
-					Some( Gender::Female ) => format!( "{} die {}", name, honor ),
-					Some( Gender::Male ) => format!( "{} der {}", name, honor ),
-					Some( Gender::Neutral ) => format!( "{} das {}", name, honor ),
-					_ => format!( "{} {}", name, honor ),
-				};
-				Ok( res )
+				let article = self.honor_article( locale )?;
+				let honor = self.designate_with( NameCombo::Honor, GrammaticalCase::Nominative, opts )?;
+				let english = locale.language.as_str() == "en";
+
+				// German would need to decline the article and the adjective of the honorific name (Bsp. "Penelope der Großen"), which is not supported.
+				if !english && article.is_some() && case != GrammaticalCase::Nominative {
+					return Err( NameError::NotExpressionable(
+						format!( "Honorific name with article cannot be inflected: {}", honor )
+					) );
+				}
+
+				// English inflects only the end of the designation (Bsp. "Alexander the Great's"), German only the forename (Bsp. "Elisabeths von Thüringen").
+				let name = self.designate_with( NameCombo::Firstname, if english { GrammaticalCase::Nominative } else { case }, opts )?;
+				let res = [ Some( name.as_str() ), article, Some( honor.as_str() ) ]
+					.into_iter()
+					.flatten()
+					.collect::<Vec<&str>>()
+					.join( " " );
+				match english {
+					true => add_case_letter( res, case, locale ),
+					false => Ok( res.into() ),
+				}
 			},
-			NameCombo::OrderedName => {
-				let names = [
-					self.firstname(),
-					self.predicate.as_deref(),
-				];
-				let res = format!( "{}, {}",
-					self.surname.as_ref().ok_or( NameError::MissingNameElement( "surname".to_string() ) )?,
-					names.iter()
-						.filter_map( |&x| x )
-						.collect::<Vec<&str>>()
-						.join( " " )
-				);
-				add_case_letter( &res, case, locale )
+			NameCombo::Regnal => {
+				let regnal = self.regnal.as_ref().ok_or( NameError::MissingNameElement( "regnal".to_string() ) )?;
+				let firstname = self.firstname_res()?;
+				match locale.language.as_str() {
+					// German marks ordinal numbers by a period and declines the forename instead of the number.
+					"de" => Ok( format!( "{} {}.", add_case_letter( firstname, case, locale )?, regnal.trim_end_matches( '.' ) ).into() ),
+					_ => add_case_letter( format!( "{} {}", firstname, regnal ), case, locale ),
+				}
+			},
+			NameCombo::RegnalSpelled => {
+				let regnal = self.regnal.as_ref().ok_or( NameError::MissingNameElement( "regnal".to_string() ) )?;
+				let firstname = self.firstname_res()?;
+				if locale.language.as_str() != "en" {
+					return Err( NameError::LangNotSupported( locale.to_string() ) );
+				}
+				let ordinal = regnal_value( regnal )
+					.and_then( ordinal_english )
+					.ok_or_else( || NameError::NotExpressionable( format!( "Regnal number cannot be spelled out: {}", regnal ) ) )?;
+				add_case_letter( format!( "{} the {}", firstname, ordinal ), case, locale )
 			},
+			NameCombo::OrderedName => self.ordered_name_res( opts.order_style, false, case, locale ).map( Cow::Owned ),
+			NameCombo::OrderedNameLibrary => self.ordered_name_res( OrderStyle::PredicateBeforeSurname, false, case, locale ).map( Cow::Owned ),
+			NameCombo::OrderedFullname => self.ordered_name_res( opts.order_style, true, case, locale ).map( Cow::Owned ),
 			NameCombo::OrderedSurname => {
-				let surname = self.surname.as_ref().ok_or( NameError::MissingNameElement( "surname".to_string() ) )?;
+				let surname = add_case_letter_gendered( self.surnames_res( locale )?, case, locale, self.gender )?;
 				let res = match &self.predicate {
-					Some( x ) => format!( "{}, {}", surname, x ),
-					None => surname.clone(),
+					Some( x ) => format!( "{}, {}", surname, x ).into(),
+					None => surname,
 				};
-				add_case_letter( &res, case, locale )
+				Ok( res )
 			},
 			NameCombo::OrderedTitleName => {
-				// let firstname = self.firstname();
-				let surname = self.surname.as_ref().ok_or( NameError::MissingNameElement( "surname".to_string() ) )?;
+				let surname = add_case_letter_gendered( self.surnames_res( locale )?, case, locale, self.gender )?;
+				let titles = self.titles_string();
 				let names = [
-					self.title.as_deref(),
+					titles.as_deref(),
 					self.firstname(),
 					self.predicate.as_deref(),
 				];
@@ -711,34 +2507,86 @@ impl Names {
 						.collect::<Vec<&str>>()
 						.join( " " )
 				);
-				add_case_letter( &res, case, locale )
+				Ok( res.into() )
 			},
+			NameCombo::SurnameFirst => Ok( format!( "{} {}", self.surname_full_res( locale )?, self.firstname_res()? ).into() ),
 			NameCombo::Initials => {
-				let name = self.designate( NameCombo::Name, GrammaticalCase::Nominative, locale )?;
-				Ok( initials( &name ) )
+				let firstname = self.firstname_res()?;
+				let surname = self.surnames_res( locale )?;
+				let names = [
+					Some( initials( firstname ) ),
+					self.predicate_initials( opts.initials_style ),
+					Some( initials( &surname ) ),
+				];
+				Ok( names.into_iter().flatten().collect::<Vec<String>>().join( " " ).into() )
+			},
+			NameCombo::InitialsNoParticle => {
+				let firstname = self.firstname_res()?;
+				let surname = self.surnames_res( locale )?;
+				let names = [
+					Some( initials( firstname ) ),
+					self.predicate_initials( InitialsStyle::DropParticles ),
+					Some( initials( &surname ) ),
+				];
+				Ok( names.into_iter().flatten().collect::<Vec<String>>().join( " " ).into() )
 			},
 			NameCombo::InitialsFull => {
-				let forenames = self.designate( NameCombo::Forenames, GrammaticalCase::Nominative, locale )?;
-				let mut name_initials = initials( &format!( "{} {}", forenames, self.surname_full_res()? ) );
-				if let Some( title ) = &self.title {
-					name_initials.insert_str( 0, &format!( "{} ", title ) );
-				};
-				Ok( name_initials )
+				let forenames = self.designate_with( NameCombo::Forenames, GrammaticalCase::Nominative, opts )?;
+				let surname = self.surnames_res( locale )?;
+				let names = [
+					self.titles_string(),
+					Some( initials( &forenames ) ),
+					self.predicate_initials( opts.initials_style ),
+					Some( initials( &surname ) ),
+				];
+				Ok( names.into_iter().flatten().collect::<Vec<String>>().join( " " ).into() )
+			},
+			NameCombo::TitleInitialsShort => {
+				let title = self.titles_res()?;
+				let name = self.designate_with( NameCombo::Initials, GrammaticalCase::Nominative, opts )?;
+				Ok( format!( "{} {}", title, name ).into() )
+			},
+			NameCombo::ForenameInitials => Ok( initials( &self.forenames_string()? ).into() ),
+			NameCombo::AnonymizedSurname => {
+				let firstname = self.firstname_res()?;
+				let surname = self.surnames_res( locale )?;
+				let names = [
+					Some( firstname.to_string() ),
+					self.predicate_initials( opts.initials_style ),
+					Some( initials( &surname ) ),
+				];
+				Ok( names.into_iter().flatten().collect::<Vec<String>>().join( " " ).into() )
+			},
+			NameCombo::FirstnameMiddleInitials => {
+				let firstname = self.firstname_res()?;
+				let names = [
+					firstname.to_string(),
+					initials( &self.forenames[1..].join( " " ) ),
+				];
+				let res = self.add_suffix_case_letter(
+					self.name_ordered_res( &names.join( " " ), locale )?,
+					case,
+					locale
+				)?;
+				Ok( res.into() )
 			},
 			NameCombo::Sign => {
-				let forenames = self.designate( NameCombo::Forenames, GrammaticalCase::Nominative, locale )?;
-				let name = match &self.predicate {
-					Some( x ) => format!( "{} {}", forenames, x ),
-					None => forenames,
-				};
-				let mut name_initials = initials( &name );
-				name_initials.push_str(
-					&format!( " {}", self.surname.as_ref().ok_or( NameError::MissingNameElement( "surname".to_string() ) )? )
-				);
-				if let Some( title ) = &self.title {
-					name_initials.insert_str( 0, &format!( "{} ", title ) );
-				};
-				Ok( name_initials )
+				let forenames = self.designate_with( NameCombo::Forenames, GrammaticalCase::Nominative, opts )?;
+				let surname = self.surnames_res( locale )?;
+				let names = [
+					self.titles_string(),
+					Some( initials( &forenames ) ),
+					self.predicate_initials( opts.initials_style ),
+					Some( surname ),
+				];
+				Ok( names.into_iter().flatten().collect::<Vec<String>>().join( " " ).into() )
+			},
+			NameCombo::Callsign => {
+				let handle = self.nickname.as_deref()
+					.or( self.supername.as_deref() )
+					.or( self.firstname() )
+					.ok_or( NameError::MissingNameElement( "nickname".to_string() ) )?;
+				Ok( uppercase_locale( handle, locale ).into() )
 			},
 			NameCombo::Supername => add_case_letter(
 				self.supername.as_ref().ok_or( NameError::MissingNameElement( "supername".to_string() ) )?
@@ -747,32 +2595,277 @@ impl Names {
 			),
 			NameCombo::FirstSupername => {
 				let firstname = self.firstname_res()?;
-				let supername = self.designate( NameCombo::Supername, case, locale )?;
-				Ok( format!( "{} {}", firstname, supername ) )
+				let supername = self.designate_with( NameCombo::Supername, case, opts )?;
+				Ok( format!( "{} {}", firstname, supername ).into() )
 			},
 			NameCombo::SuperName => {
-				let supername = self.designate( NameCombo::Supername, case, locale )?;
-				add_case_letter(
-					&format!( "{} {} {}", self.firstname_res()?, supername, self.surname_full_res()? ),
+				let supername = self.designate_with( NameCombo::Supername, case, opts )?;
+				add_case_letter_gendered(
+					format!( "{} {} {}", self.firstname_res()?, supername, self.surname_full_res( locale )? ),
 					case,
-					locale
+					locale,
+					self.gender
+				)
+			},
+			NameCombo::SupernameSurname => {
+				let supername = self.supername.as_ref().ok_or( NameError::MissingNameElement( "supername".to_string() ) )?;
+				add_case_letter_gendered(
+					format!( "{} {}", supername, self.surname_full_res( locale )? ),
+					case,
+					locale,
+					self.gender
 				)
 			},
 			NameCombo::PoliteSupername => {
 				let polite = self.gender
 					.ok_or( NameError::MissingNameElement( "gender".to_string() ) )?
 					.polite( locale )?;
-				let name = self.designate( NameCombo::Supername, case, locale )?;
-				Ok( format!( "{} {}", polite, name ) )
+				let name = self.designate_with( NameCombo::Supername, case, opts )?;
+				Ok( format!( "{} {}", polite, name ).into() )
 			},
 			NameCombo::RankSupername => {
-				let rank = self.rank.as_ref().ok_or( NameError::MissingNameElement( "rank".to_string() ) )?;
-				let name = self.designate( NameCombo::Supername, case, locale )?;
-				Ok( format!( "{} {}", rank, name ) )
+				let rank = self.rank_res( locale )?;
+				let name = self.designate_with( NameCombo::Supername, case, opts )?;
+				Ok( format!( "{} {}", rank, name ).into() )
 			},
 		}
 	}
 
+	/// Returns a key to sort names by. The key consists of the surname followed by the first forename, all in lowercase and without diacritics (see below). The predicate is ignored, so "Penelope von Würzinger" is sorted under "W". Missing name elements are skipped.
+	///
+	/// Letters with diacritics are replaced by their base letters. The German umlauts and "ß" are replaced according to the German phone book rule ("ü" => "ue", "ß" => "ss").
+	///
+	/// Bsp. "Penelope von Würzinger" => "wuerzinger penelope"
+	pub fn sort_key( &self ) -> String {
+		let surnames = self.surnames_res( &DEFAULT_LOCALE ).ok();
+		let names = [
+			surnames.as_deref(),
+			self.firstname(),
+		];
+
+		let res = names.iter()
+			.filter_map( |&x| x )
+			.collect::<Vec<&str>>()
+			.join( " " );
+
+		fold_diacritics( &res ).to_lowercase()
+	}
+
+	/// Returns a key identifying the person for deduplication. The key consists of the forenames, the full surname (including predicate and second surname) and the birthname, each in lowercase with collapsed whitespace and separated by "|". Missing name elements result in empty parts. All other name elements (like titles, rank, nickname or gender) are ignored, so "Dr. Penelope von Würzinger" and "Penelope von Würzinger" have the same key.
+	///
+	/// Bsp. "Penelope Karin von Würzinger geb. Stauff" => "penelope karin|von würzinger|stauff"
+	pub fn identity_key( &self ) -> String {
+		let parts = [
+			self.forenames.join( " " ),
+			self.surname_full_res( &DEFAULT_LOCALE ).unwrap_or_default(),
+			self.birthname.clone().unwrap_or_default(),
+		];
+
+		parts.iter()
+			.map( |x| collapse_whitespace( Cow::Borrowed( x ) ).to_lowercase() )
+			.collect::<Vec<String>>()
+			.join( "|" )
+	}
+
+	/// Returns all name elements of `self` in a compact text format that can be read by `from_compact()` without loss.
+	///
+	/// The format starts with the version marker "v1", followed by these fields separated by "|": forenames, patronymic, predicate, surname, second surname, suffix, birthname, titles, rank, rank key, nickname, honorname, supername, regnal number, gender, the rank feminization, the surname feminization and the index of the Rufname. Multiple forenames and titles are separated by ",". Backslashes, "|" and "," within names are escaped by a preceding backslash. Missing name elements result in empty fields. The gender is written as text (Bsp. "female"), an enabled feminization as "1" and the index of the Rufname in digits.
+	///
+	/// Fields are only ever appended to the end of this schema. Later versions of this crate therefore write the same version marker and still read names written by earlier versions, which lack the trailing fields.
+	///
+	/// Bsp. "v1|Penelope,Karin||von|Würzinger|||Stauff|Dr.|||||||female|||1"
+	pub fn to_compact( &self ) -> String {
+		let list = |x: &Vec<String>| x.iter()
+			.map( |y| compact_escape( y ) )
+			.collect::<Vec<String>>()
+			.join( "," );
+		let opt = |x: &Option<String>| x.as_deref()
+			.map( compact_escape )
+			.unwrap_or_default();
+
+		[
+			COMPACT_VERSION.to_string(),
+			list( &self.forenames ),
+			opt( &self.patronymic ),
+			opt( &self.predicate ),
+			opt( &self.surname ),
+			opt( &self.surname_second ),
+			opt( &self.suffix ),
+			opt( &self.birthname ),
+			list( &self.titles ),
+			opt( &self.rank ),
+			opt( &self.rank_key ),
+			opt( &self.nickname ),
+			opt( &self.honorname ),
+			opt( &self.supername ),
+			opt( &self.regnal ),
+			self.gender.map( |x| x.to_string() ).unwrap_or_default(),
+			if self.rank_feminize { "1".to_string() } else { String::new() },
+			if self.surname_feminize { "1".to_string() } else { String::new() },
+			self.rufname_index.map( |x| x.to_string() ).unwrap_or_default(),
+		].join( "|" )
+	}
+
+	/// Reads name elements written by `to_compact()`. Missing trailing fields are treated as empty, so names written by earlier versions of this crate can still be read. The name elements are set by the respective `with_*` methods, so their whitespace is trimmed and empty names are ignored. The result is checked for consistency by `validate()`.
+	///
+	/// # Error
+	/// If `text` does not start with the version marker, contains too many fields or the index of the Rufname is no number, this method returns `NameError::IllegalCombo`. If the gender is unknown or the name elements are inconsistent (see `validate()`), the respective error is returned.
+	pub fn from_compact( text: &str ) -> Result<Self, NameError> {
+		let mut fields = compact_split( text, '|' );
+		if fields.first() != Some( &COMPACT_VERSION ) || fields.len() > COMPACT_FIELDS + 1 {
+			#[cfg( feature = "log" )]
+			error!( "{:?} is not a name in compact format.", text );
+			return Err( NameError::IllegalCombo );
+		}
+		fields.resize( COMPACT_FIELDS + 1, "" );
+		let [ _, forenames, patronymic, predicate, surname, surname_second, suffix, birthname, titles, rank, rank_key, nickname, honorname, supername, regnal, gender, rank_feminize, surname_feminize, rufname_index ] = fields[..] else {
+			return Err( NameError::IllegalCombo );
+		};
+
+		let list = |x: &str| if x.is_empty() {
+			Vec::new()
+		} else {
+			compact_split( x, ',' ).into_iter().map( compact_unescape ).collect()
+		};
+		let opt = |x: &str| if x.is_empty() {
+			None
+		} else {
+			Some( compact_unescape( x ) )
+		};
+
+		let gender = match gender {
+			"" => None,
+			x => Some( Gender::from_str( x )? ),
+		};
+		let titles = list( titles );
+
+		let mut res = Self::new()
+			.with_forenames_iter( list( forenames ) )
+			.with_patronymic_opt( opt( patronymic ).as_deref() )
+			.with_predicate_opt( opt( predicate ).as_deref() )
+			.with_surname_opt( opt( surname ).as_deref() )
+			.with_second_surname_opt( opt( surname_second ).as_deref() )
+			.with_suffix_opt( opt( suffix ).as_deref() )
+			.with_birthname_opt( opt( birthname ).as_deref() )
+			.with_titles( &titles.iter().map( String::as_str ).collect::<Vec<&str>>() )
+			.with_rank_opt( opt( rank ).as_deref() )
+			.with_rank_key_opt( opt( rank_key ).as_deref() )
+			.with_nickname_opt( opt( nickname ).as_deref() )
+			.with_honorname_opt( opt( honorname ).as_deref() )
+			.with_supername_opt( opt( supername ).as_deref() )
+			.with_regnal_opt( opt( regnal ).as_deref() )
+			.with_gender_opt( gender )
+			.with_rank_feminize( rank_feminize == "1" )
+			.with_surname_feminize( surname_feminize == "1" );
+
+		if !rufname_index.is_empty() {
+			res.set_rufname_index( Some( rufname_index.parse::<usize>().map_err( |_| NameError::IllegalCombo )? ) );
+		}
+
+		res.validate()?;
+		Ok( res )
+	}
+
+
+	/// This method returns how a persone with the name elements in `self` can be called according to the chose `form` in a specific language (`locale`). If `self` cannot be expressed with `form` (maybe a relevant name part is missing), this method returns an error. A missing name element results in `NameError::CannotExpress` carrying `form` and the missing element.
+	///
+	/// # Arguments
+	/// * `form` The name combination.
+	/// * `case` the grammatical case.
+	/// * `locale` the locale to use the grammatical rules of. Currently only English and German are supported.
+	///
+	/// # Returns
+	/// Returns the calling of the name. Runs of whitespace are collapsed into a single space and leading or trailing whitespace is removed.
+	pub fn designate( &self, form: NameCombo, case: GrammaticalCase, locale: &LanguageIdentifier ) -> Result<String, NameError> {
+		self.designate_cow( form, case, locale ).map( Cow::into_owned )
+	}
+
+	/// Works like [`Names::designate`] but takes the locale as language tag (Bsp. "de" or "de-DE"), so callers do not need to create a `LanguageIdentifier`. If `lang` is no valid language tag, this method returns `NameError::LangNotSupported`.
+	///
+	/// # Arguments
+	/// * `form` The name combination.
+	/// * `case` the grammatical case.
+	/// * `lang` the language tag of the locale to use the grammatical rules of.
+	pub fn designate_str( &self, form: NameCombo, case: GrammaticalCase, lang: &str ) -> Result<String, NameError> {
+		let locale = LanguageIdentifier::from_str( lang )
+			.map_err( |_| NameError::LangNotSupported( lang.to_string() ) )?;
+		self.designate( form, case, &locale )
+	}
+
+	/// Returns the designation of `self` according to `form` in the nominative case. This is a shortcut for [`Names::designate`] with `GrammaticalCase::Nominative`.
+	///
+	/// # Arguments
+	/// * `form` The name combination.
+	/// * `locale` the locale to use the grammatical rules of.
+	pub fn nominative( &self, form: NameCombo, locale: &LanguageIdentifier ) -> Result<String, NameError> {
+		self.designate( form, GrammaticalCase::Nominative, locale )
+	}
+
+	/// Returns the number of characters of the designation of `self` according to `form` in the nominative case. Bsp. 22 for "Penelope von Würzinger". This helps to choose a name combination fitting into a limited space. If `self` cannot be expressed with `form`, this method returns the same error as [`Names::designate`].
+	///
+	/// # Arguments
+	/// * `form` The name combination.
+	/// * `locale` the locale to use the grammatical rules of.
+	pub fn rendered_len( &self, form: NameCombo, locale: &LanguageIdentifier ) -> Result<usize, NameError> {
+		self.designate_cow( form, GrammaticalCase::Nominative, locale ).map( |x| x.chars().count() )
+	}
+
+	/// Works like [`Names::designate`] but tries all `locales` in order until one of them is supported. Bsp. `[ de-AT, de-DE ]` uses the Austrian rules if available and falls back to the German rules otherwise. Errors other than `NameError::LangNotSupported` are returned immediately. If no locale is supported, the error of the last locale is returned. If `locales` is empty, this method returns `NameError::LangNotSupported` with an empty locale.
+	///
+	/// # Arguments
+	/// * `form` The name combination.
+	/// * `case` the grammatical case.
+	/// * `locales` the locales to try in order of preference.
+	pub fn designate_with_fallback( &self, form: NameCombo, case: GrammaticalCase, locales: &[LanguageIdentifier] ) -> Result<String, NameError> {
+		let mut err = NameError::LangNotSupported( String::new() );
+
+		for locale in locales {
+			match self.designate( form, case, locale ) {
+				Err( e @ NameError::LangNotSupported( _ ) ) => err = e,
+				res => return res,
+			}
+		}
+
+		Err( err )
+	}
+
+	/// Works like [`Names::designate`] but distinguishes between name elements missing in `self` and errors that are not caused by the content of `self`. If `self` cannot be expressed with `form` due to missing name elements, this method returns `Ok( None )`. Other errors (like an unsupported `locale`) are returned as errors.
+	///
+	/// # Arguments
+	/// * `form` The name combination.
+	/// * `case` the grammatical case.
+	/// * `locale` the locale to use the grammatical rules of.
+	pub fn try_designate( &self, form: NameCombo, case: GrammaticalCase, locale: &LanguageIdentifier ) -> Result<Option<String>, NameError> {
+		match self.designate( form, case, locale ) {
+			Ok( x ) => Ok( Some( x ) ),
+			Err( NameError::MissingNameElement( _ ) | NameError::CannotExpress { .. } | NameError::IllegalCombo ) => Ok( None ),
+			Err( e ) => Err( e ),
+		}
+	}
+
+	/// Returns every designation of `self` that can be rendered in `case` and `locale`, in the order of [`NameCombo::ALL`]. Forms that result in an error are skipped.
+	///
+	/// # Arguments
+	/// * `case` the grammatical case.
+	/// * `locale` the locale to use the grammatical rules of.
+	pub fn designate_all( &self, case: GrammaticalCase, locale: &LanguageIdentifier ) -> Vec<( NameCombo, String )> {
+		NameCombo::ALL.iter()
+			.filter_map( |&form| self.designate( form, case, locale ).ok().map( |x| ( form, x ) ) )
+			.collect()
+	}
+
+	/// Returns the designations of `self` for all name combinations in `forms`, in the same order. Unlike [`Names::designate_all`], name combinations that cannot be expressed are kept as errors.
+	///
+	/// # Arguments
+	/// * `forms` The name combinations.
+	/// * `case` the grammatical case.
+	/// * `locale` the locale to use the grammatical rules of.
+	pub fn designate_many( &self, forms: &[NameCombo], case: GrammaticalCase, locale: &LanguageIdentifier ) -> Vec<Result<String, NameError>> {
+		forms.iter()
+			.map( |&form| self.designate( form, case, locale ) )
+			.collect()
+	}
+
 	/// Returns a designation by following the following list of precedence, returning the first that is possible. If none of the provided alternatives is available, this function returns `None`.
 	///
 	/// 1. `NameCombo::Fullname`
@@ -781,7 +2874,7 @@ impl Names {
 	/// 4. `NameCombo::Nickname`
 	/// 5. `NameCombo::Supername`
 	///
-	/// If the first choice is not available, the next item is tried and so forth until one option is available or none are, in which case this function returns `None`.
+	/// If the first choice is not available, the next item is tried and so forth until one option is available or none are, in which case this function returns an error listing all missing name elements (see [`Names::moniker_with`]).
 	///
 	/// # Arguments
 	/// * `case` The grammatical case the name will be transformed into.
@@ -790,32 +2883,278 @@ impl Names {
 		case: GrammaticalCase,
 		locale: &LanguageIdentifier
 	) -> Result<String, NameError> {
-		self.designate( NameCombo::Fullname, case, locale )
-			.or( self.designate( NameCombo::Firstname, case, locale )
-				.or( self.designate( NameCombo::Surname, case, locale )
-					.or( self.designate( NameCombo::Nickname, case, locale )
-						.or( self.designate( NameCombo::Supername, case, locale ) )
-					)
-				)
-			)
+		self.moniker_with( &MONIKER_PRECEDENCE, case, locale )
 	}
-}
-
-
-
 
-//=============================================================================
-// Testing
+	/// Returns the designation of the first name combination of `order` that is possible. If none of the name combinations is possible due to missing name elements, this method returns a `NameError::MissingNameElement` listing all missing elements separated by a slash (Bsp. "forenames/surname"). If any other error occurred, the last of these errors is returned instead. If `order` is empty, this method returns `NameError::IllegalCombo`.
+	///
+	/// # Arguments
+	/// * `order` The name combinations to try in order of precedence.
+	/// * `case` The grammatical case the name will be transformed into.
+	/// * `locale` the locale to use the grammatical rules of.
+	pub fn moniker_with(
+		&self,
+		order: &[NameCombo],
+		case: GrammaticalCase,
+		locale: &LanguageIdentifier
+	) -> Result<String, NameError> {
+		let mut missing: Vec<String> = Vec::new();
+		let mut err = None;
+
+		for form in order {
+			match self.designate( *form, case, locale ) {
+				Ok( x ) => return Ok( x ),
+				Err( NameError::MissingNameElement( x ) | NameError::CannotExpress { missing: x, .. } ) => {
+					if !missing.contains( &x ) {
+						missing.push( x );
+					}
+				},
+				Err( e ) => err = Some( e ),
+			}
+		}
 
+		if let Some( e ) = err {
+			return Err( e );
+		}
 
-#[cfg( test )]
-mod tests {
-	use super::*;
+		if missing.is_empty() {
+			return Err( NameError::IllegalCombo );
+		}
 
-	#[test]
-	fn grammatical_case_from_str() {
-		assert_eq!( GrammaticalCase::from_str( "nominative" ).unwrap(), GrammaticalCase::Nominative );
-		assert_eq!( GrammaticalCase::from_str( "Dative" ).unwrap(), GrammaticalCase::Dative );
+		Err( NameError::MissingNameElement( missing.join( "/" ) ) )
+	}
+
+	/// Returns the designation of `self` according to `form` or, if that is not possible, according to `fallback`. This is a shortcut for [`Names::moniker_with`] with just these two name combinations and returns the same errors if both fail.
+	///
+	/// # Arguments
+	/// * `form` The preferred name combination.
+	/// * `case` The grammatical case the name will be transformed into.
+	/// * `locale` the locale to use the grammatical rules of.
+	/// * `fallback` The name combination to use if `form` is not possible.
+	pub fn designate_or( &self, form: NameCombo, case: GrammaticalCase, locale: &LanguageIdentifier, fallback: NameCombo ) -> Result<String, NameError> {
+		self.moniker_with( &[ form, fallback ], case, locale )
+	}
+}
+
+/// Writes the name of the person according to the precedence of [`Names::moniker`] in the nominative case using the English (`en-US`) rules. If no name element is available at all, `<unnamed>` is written instead.
+///
+/// This representation is lossy and ignores the locale of the user. It is meant for logging and debugging. Use [`Names::designate`] whenever the correct designation in a specific language matters.
+impl fmt::Display for Names {
+	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result {
+		match self.moniker( GrammaticalCase::Nominative, &DEFAULT_LOCALE ) {
+			Ok( x ) => write!( f, "{}", x ),
+			Err( _ ) => write!( f, "<unnamed>" ),
+		}
+	}
+}
+
+/// Converts the name of the person according to the precedence of [`Names::moniker`] in the nominative case using the English (`en-US`) rules. If no name element is available at all, the result is an empty string.
+///
+/// Like the `Display` implementation, this conversion is lossy and ignores the locale of the user. Use [`Names::designate`] whenever the correct designation in a specific language matters.
+impl From<&Names> for String {
+	fn from( names: &Names ) -> Self {
+		names.moniker( GrammaticalCase::Nominative, &DEFAULT_LOCALE ).unwrap_or_default()
+	}
+}
+
+/// Converts the name of the person like `From<&Names>`.
+impl From<Names> for String {
+	fn from( names: Names ) -> Self {
+		String::from( &names )
+	}
+}
+
+/// Orders names by their [`Names::sort_key`], so names are sorted by surname first with diacritics folded. Names with the same sort key are ordered by their remaining name elements to keep the ordering consistent with `Eq`.
+///
+/// This ordering ignores the locale and is meant for lists on display. It is no replacement for a collation that is correct for a specific language.
+impl Ord for Names {
+	fn cmp( &self, other: &Self ) -> Ordering {
+		self.sort_key().cmp( &other.sort_key() )
+			.then_with( || self.forenames.cmp( &other.forenames ) )
+			.then_with( || self.rufname_index.cmp( &other.rufname_index ) )
+			.then_with( || self.patronymic.cmp( &other.patronymic ) )
+			.then_with( || self.predicate.cmp( &other.predicate ) )
+			.then_with( || self.surname.cmp( &other.surname ) )
+			.then_with( || self.surname_second.cmp( &other.surname_second ) )
+			.then_with( || self.suffix.cmp( &other.suffix ) )
+			.then_with( || self.birthname.cmp( &other.birthname ) )
+			.then_with( || self.titles.cmp( &other.titles ) )
+			.then_with( || self.rank.cmp( &other.rank ) )
+			.then_with( || self.rank_key.cmp( &other.rank_key ) )
+			.then_with( || self.nickname.cmp( &other.nickname ) )
+			.then_with( || self.honorname.cmp( &other.honorname ) )
+			.then_with( || self.supername.cmp( &other.supername ) )
+			.then_with( || self.regnal.cmp( &other.regnal ) )
+			.then_with( || self.gender.cmp( &other.gender ) )
+			.then_with( || self.rank_feminize.cmp( &other.rank_feminize ) )
+			.then_with( || self.surname_feminize.cmp( &other.surname_feminize ) )
+	}
+}
+
+impl PartialOrd for Names {
+	fn partial_cmp( &self, other: &Self ) -> Option<Ordering> {
+		Some( self.cmp( other ) )
+	}
+}
+
+/// Collects the forenames of a new `Names` like `Names::with_forenames_iter()`. Bsp. `[ "Thomas", "Jakob" ].into_iter().collect::<Names>()`.
+impl<S: AsRef<str>> FromIterator<S> for Names {
+	fn from_iter<I: IntoIterator<Item = S>>( iter: I ) -> Self {
+		Names::new().with_forenames_iter( iter )
+	}
+}
+
+/// Parses a name of the form "forenames [particles] surname" strictly. Bsp. "Thomas Jakob von Würzinger" => forenames "Thomas" and "Jakob", predicate "von", surname "Würzinger".
+///
+/// Every forename and the surname have to start with an uppercase letter and may only consist of letters, hyphens and apostrophes. Lowercase words are only accepted as name particles ("von", "van", "de", "der", "den", "di", "du", "la", "le" and "zu") directly in front of the surname. At least one forename and exactly one surname are required. If the input does not follow this structure, this returns `NameError::IllegalCombo` instead of guessing.
+impl TryFrom<&str> for Names {
+	type Error = NameError;
+
+	fn try_from( s: &str ) -> Result<Self, Self::Error> {
+		let is_name = |x: &str| x.chars().next().is_some_and( |y| y.is_uppercase() )
+			&& x.chars().all( |y| y.is_alphabetic() || y == '-' || y == '\'' );
+
+		let words: Vec<&str> = s.split_whitespace().collect();
+		let Some( ( surname, rest ) ) = words.split_last() else {
+			#[cfg( feature = "log" )]
+			error!( "{:?} does not contain a name.", s );
+			return Err( NameError::IllegalCombo );
+		};
+		let count = rest.iter()
+			.rev()
+			.take_while( |x| SURNAME_PARTICLES.contains( x ) )
+			.count();
+		let ( forenames, particles ) = rest.split_at( rest.len() - count );
+
+		if forenames.is_empty() || !is_name( surname ) || !forenames.iter().all( |x| is_name( x ) ) {
+			#[cfg( feature = "log" )]
+			error!( "{:?} is not a name of the form \"forenames [particles] surname\".", s );
+			return Err( NameError::IllegalCombo );
+		}
+
+		Ok( Names::new()
+			.with_forenames( forenames )
+			.with_predicate( &particles.join( " " ) )
+			.with_surname( surname ) )
+	}
+}
+
+
+/// Forwards the `with_*` methods of `Names` to `NamesBuilder`.
+macro_rules! forward_builder {
+	( $( $name:ident( $arg:ident: $ty:ty ); )* ) => {
+		$(
+			#[doc = concat!( "Works like [`Names::", stringify!( $name ), "`]." )]
+			pub fn $name( self, $arg: $ty ) -> Self {
+				Self( self.0.$name( $arg ) )
+			}
+		)*
+	};
+}
+
+
+/// Builds a `Names` that is checked for consistency when finished by `build()`. The builder offers the same `with_*` methods as `Names`. Bsp. `Names::builder().with_surname( "Würzinger" ).build()`.
+#[derive( Clone, PartialEq, Eq, Default, Debug )]
+pub struct NamesBuilder( Names );
+
+impl NamesBuilder {
+	forward_builder! {
+		with_forenames( names: &[&str] );
+		with_hyphenated_forename( parts: &[&str] );
+		with_forename( name: &str );
+		with_rufname_index( index: usize );
+		with_patronymic( name: &str );
+		with_predicate( name: &str );
+		with_surname( name: &str );
+		with_surname_parts( full: &str );
+		with_second_surname( name: &str );
+		with_suffix( suffix: &str );
+		with_birthname( name: &str );
+		with_title( title: &str );
+		with_titles( titles: &[&str] );
+		with_rank( rank: &str );
+		with_rank_key( key: &str );
+		with_nickname( name: &str );
+		with_honorname( name: &str );
+		with_supername( name: &str );
+		with_regnal( number: &str );
+		with_gender( gender: impl Borrow<Gender> );
+		with_rank_feminize( feminize: bool );
+		with_surname_feminize( feminize: bool );
+		with_forename_opt( name: Option<&str> );
+		with_patronymic_opt( name: Option<&str> );
+		with_predicate_opt( name: Option<&str> );
+		with_surname_opt( name: Option<&str> );
+		with_second_surname_opt( name: Option<&str> );
+		with_suffix_opt( suffix: Option<&str> );
+		with_birthname_opt( name: Option<&str> );
+		with_title_opt( title: Option<&str> );
+		with_rank_opt( rank: Option<&str> );
+		with_rank_key_opt( key: Option<&str> );
+		with_nickname_opt( name: Option<&str> );
+		with_honorname_opt( name: Option<&str> );
+		with_supername_opt( name: Option<&str> );
+		with_regnal_opt( number: Option<&str> );
+		with_gender_opt( gender: Option<Gender> );
+	}
+
+	/// Works like [`Names::with_forenames_iter`].
+	pub fn with_forenames_iter<I, S>( self, names: I ) -> Self
+	where
+		I: IntoIterator<Item = S>,
+		S: AsRef<str>,
+	{
+		Self( self.0.with_forenames_iter( names ) )
+	}
+
+	/// Works like [`Names::with_gender_str`].
+	pub fn with_gender_str( self, gender: &str ) -> Result<Self, NameError> {
+		self.0.with_gender_str( gender ).map( Self )
+	}
+
+	/// Finish building by checking the name elements for consistency (see `Names::validate()`). If they are inconsistent, this method returns an error.
+	pub fn build( self ) -> Result<Names, NameError> {
+		self.0.validate()?;
+		Ok( self.0 )
+	}
+}
+
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use alloc::vec;
+
+	use super::*;
+
+	#[test]
+	fn grammatical_case_from_str() {
+		assert_eq!( GrammaticalCase::from_str( "nominative" ).unwrap(), GrammaticalCase::Nominative );
+		assert_eq!( GrammaticalCase::from_str( "Dative" ).unwrap(), GrammaticalCase::Dative );
+		assert_eq!( GrammaticalCase::from_str( "vocative" ).unwrap(), GrammaticalCase::Vocative );
+		assert_eq!( GrammaticalCase::from_str( "Vocative" ).unwrap(), GrammaticalCase::Vocative );
+		assert_eq!( GrammaticalCase::from_str( "ablative" ), Err( NameError::IllegalCase ) );
+	}
+
+	#[test]
+	fn grammatical_case_from_str_abbreviated() {
+		assert_eq!( GrammaticalCase::from_str( "n" ).unwrap(), GrammaticalCase::Nominative );
+		assert_eq!( GrammaticalCase::from_str( "Nom" ).unwrap(), GrammaticalCase::Nominative );
+		assert_eq!( GrammaticalCase::from_str( "g" ).unwrap(), GrammaticalCase::Genetive );
+		assert_eq!( GrammaticalCase::from_str( "GEN" ).unwrap(), GrammaticalCase::Genetive );
+		assert_eq!( GrammaticalCase::from_str( "s" ).unwrap(), GrammaticalCase::Genetive );
+		assert_eq!( GrammaticalCase::from_str( "D" ).unwrap(), GrammaticalCase::Dative );
+		assert_eq!( GrammaticalCase::from_str( "dat" ).unwrap(), GrammaticalCase::Dative );
+		assert_eq!( GrammaticalCase::from_str( "a" ).unwrap(), GrammaticalCase::Accusative );
+		assert_eq!( GrammaticalCase::from_str( "Acc" ).unwrap(), GrammaticalCase::Accusative );
+		assert_eq!( GrammaticalCase::from_str( "v" ).unwrap(), GrammaticalCase::Vocative );
+		assert_eq!( GrammaticalCase::from_str( "voc" ).unwrap(), GrammaticalCase::Vocative );
+		assert_eq!( GrammaticalCase::from_str( "x" ), Err( NameError::IllegalCase ) );
 	}
 
 	#[test]
@@ -890,489 +3229,2986 @@ mod tests {
 			add_case_letter( "Aristoteles", GrammaticalCase::Accusative, &GERMAN ).unwrap(),
 			"Aristoteles"
 		);
+		assert_eq!(
+			add_case_letter( "Gunther", GrammaticalCase::Vocative, &US_ENGLISH ).unwrap(),
+			"Gunther"
+		);
+		assert_eq!(
+			add_case_letter( "Günther", GrammaticalCase::Vocative, &GERMAN ).unwrap(),
+			"Günther"
+		);
 	}
 
 	#[test]
-	fn name_combo_from_str() {
-		assert_eq!( NameCombo::from_str( "Name" ).unwrap(), NameCombo::Name );
-		assert_eq!( NameCombo::from_str( "PoliteTitleName" ).unwrap(), NameCombo::PoliteTitleName );
+	fn test_add_case_letter_italian() {
+		use unic_langid::LanguageIdentifier;
+		use unic_langid::langid;
+
+		const ITALIAN: LanguageIdentifier = langid!( "it-IT" );
+
+		assert_eq!(
+			add_case_letter( "Rossi", GrammaticalCase::Nominative, &ITALIAN ).unwrap(),
+			"Rossi"
+		);
+		assert_eq!(
+			add_case_letter( "Rossi", GrammaticalCase::Genetive, &ITALIAN ).unwrap(),
+			"Rossi"
+		);
 	}
 
 	#[test]
-	fn test_initials() {
-		assert_eq!( initials( "Test Test" ), "T. T.".to_string() );
-		assert_eq!( initials( "Thomas von Würzinger" ), "T. v. W.".to_string() );
+	fn test_add_case_letter_greek() {
+		use unic_langid::LanguageIdentifier;
+		use unic_langid::langid;
+
+		const GREEK: LanguageIdentifier = langid!( "el-GR" );
+
+		assert_eq!(
+			add_case_letter( "Aristoteles", GrammaticalCase::Nominative, &GREEK ).unwrap(),
+			"Aristoteles"
+		);
+		assert_eq!(
+			add_case_letter( "Aristoteles", GrammaticalCase::Genetive, &GREEK ).unwrap(),
+			"Aristotelous"
+		);
+		assert_eq!(
+			add_case_letter( "Nikos Papadopoulos", GrammaticalCase::Genetive, &GREEK ).unwrap(),
+			"Nikos Papadopoulou"
+		);
+		assert_eq!(
+			add_case_letter( "Loukas", GrammaticalCase::Genetive, &GREEK ).unwrap(),
+			"Louka"
+		);
+		assert_eq!(
+			add_case_letter( "Eleni", GrammaticalCase::Genetive, &GREEK ).unwrap(),
+			"Eleni"
+		);
 	}
 
 	#[test]
-	fn create_names() {
-		assert_eq!( Names::new(), Names::default() );
-		assert_eq!( Names::new()
-			.with_forenames( &vec![ "Test1", "Test2" ] ), Names {
-				forenames: vec![ "Test1".to_string(), "Test2".to_string() ],
-				..Default::default()
-			}
+	fn test_add_case_letter_swedish() {
+		use unic_langid::LanguageIdentifier;
+		use unic_langid::langid;
+
+		const SWEDISH: LanguageIdentifier = langid!( "sv-SE" );
+
+		assert_eq!(
+			add_case_letter( "Andersson", GrammaticalCase::Nominative, &SWEDISH ).unwrap(),
+			"Andersson"
 		);
-		assert_eq!( Names::new()
-			.with_predicate( "Test" ), Names {
-				predicate: Some( "Test".to_string() ),
-				..Default::default()
-			}
+		assert_eq!(
+			add_case_letter( "Andersson", GrammaticalCase::Genetive, &SWEDISH ).unwrap(),
+			"Anderssons"
 		);
-		assert_eq!( Names::new()
-			.with_surname( "Test" ), Names {
-				surname: Some( "Test".to_string() ),
-				..Default::default()
-			}
+		assert_eq!(
+			add_case_letter( "Lars", GrammaticalCase::Genetive, &SWEDISH ).unwrap(),
+			"Lars"
 		);
-		assert_eq!( Names::new()
-			.with_birthname( "Test" ), Names {
-				birthname: Some( "Test".to_string() ),
-				..Default::default()
-			}
+		assert_eq!(
+			add_case_letter( "Marx", GrammaticalCase::Genetive, &SWEDISH ).unwrap(),
+			"Marx"
 		);
-		assert_eq!( Names::new()
-			.with_title( "Test" ), Names {
-				title: Some( "Test".to_string() ),
-				..Default::default()
-			}
+	}
+
+	#[test]
+	fn test_add_case_letter_punctuation() {
+		use unic_langid::LanguageIdentifier;
+		use unic_langid::langid;
+
+		const US_ENGLISH: LanguageIdentifier = langid!( "en-US" );
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+
+		assert_eq!(
+			add_case_letter( "Max.", GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"Max'."
 		);
-		assert_eq!( Names::new()
-			.with_rank( "Test" ), Names {
-				rank: Some( "Test".to_string() ),
-				..Default::default()
-			}
+		assert_eq!(
+			add_case_letter( "Ben.", GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"Bens."
 		);
-		assert_eq!( Names::new()
-			.with_nickname( "Test" ), Names {
-				nickname: Some( "Test".to_string() ),
-				..Default::default()
-			}
+		assert_eq!(
+			add_case_letter( "„Würzi“", GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"„Würzis“"
 		);
-		assert_eq!( Names::new()
-			.with_honorname( "Test" ), Names {
-				honorname: Some( "Test".to_string() ),
-				..Default::default()
-			}
+		assert_eq!(
+			add_case_letter( "Thomas (Würzi)", GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"Thomas (Würzis)"
 		);
-		assert_eq!( Names::new()
-			.with_supername( "Test" ), Names {
-				supername: Some( "Test".to_string() ),
-				..Default::default()
-			}
+		assert_eq!(
+			add_case_letter( "\"Jack\"", GrammaticalCase::Genetive, &US_ENGLISH ).unwrap(),
+			"\"Jack's\""
 		);
-		assert_eq!( Names::new()
-			.with_gender( &Gender::Female ), Names {
-				gender: Some( Gender::Female ),
-				..Default::default()
-			}
+		assert_eq!(
+			add_case_letter( "Jr.", GrammaticalCase::Genetive, &US_ENGLISH ).unwrap(),
+			"Jr.'s"
+		);
+		assert_eq!(
+			add_case_letter( "\"\"", GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"\"\""
 		);
 	}
 
 	#[test]
-	fn name_strings_male() {
+	fn test_add_case_letter_french() {
+		use unic_langid::LanguageIdentifier;
 		use unic_langid::langid;
 
-		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
-
-		// Thomas Jakob von Würzinger
-		let name = Names {
-			forenames: [ "Thomas", "Jakob" ].iter().map( |x| x.to_string() ).collect(),
-			predicate: Some( "von".to_string() ),
-			surname: Some( "Würzinger".to_string() ),
-			birthname: None,
-			title: None,
-			rank: Some( "Hauptkommissar".to_string() ),
-			nickname: Some( "Würzi".to_string() ),
-			honorname: Some( "Dunkle".to_string() ),
-			supername: Some( "Würzt-das-Essen".to_string() ),
-			gender: Some( Gender::Male ),
-		};
+		const FRENCH: LanguageIdentifier = langid!( "fr-FR" );
 
 		assert_eq!(
-			name.designate( NameCombo::Name, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Thomas von Würzinger".to_string()
+			add_case_letter( "Dupont", GrammaticalCase::Nominative, &FRENCH ).unwrap(),
+			"Dupont"
 		);
 		assert_eq!(
-			name.designate( NameCombo::Name, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
-			"Thomas von Würzingers".to_string()
+			add_case_letter( "Yves", GrammaticalCase::Nominative, &FRENCH ).unwrap(),
+			"Yves"
 		);
 		assert_eq!(
-			name.designate( NameCombo::Name, GrammaticalCase::Accusative, &GERMAN ).unwrap(),
-			"Thomas von Würzinger".to_string()
+			add_case_letter( "Dupont", GrammaticalCase::Genetive, &FRENCH ).unwrap(),
+			"Dupont"
 		);
-
 		assert_eq!(
-			name.designate( NameCombo::Surname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"von Würzinger".to_string()
+			add_case_letter( "Yves", GrammaticalCase::Genetive, &FRENCH ).unwrap(),
+			"Yves"
 		);
-
 		assert_eq!(
-			name.designate( NameCombo::Firstname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Thomas".to_string()
+			add_case_letter( "Dupont", GrammaticalCase::Dative, &FRENCH ).unwrap(),
+			"Dupont"
 		);
 		assert_eq!(
-			name.designate( NameCombo::Firstname, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
-			"Thomas'".to_string()
+			add_case_letter( "Yves", GrammaticalCase::Dative, &FRENCH ).unwrap(),
+			"Yves"
 		);
-
 		assert_eq!(
-			name.designate( NameCombo::Forenames, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Thomas Jakob".to_string()
+			add_case_letter( "Dupont", GrammaticalCase::Accusative, &FRENCH ).unwrap(),
+			"Dupont"
 		);
-
 		assert_eq!(
-			name.designate( NameCombo::Fullname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Thomas Jakob von Würzinger".to_string()
+			add_case_letter( "Yves", GrammaticalCase::Accusative, &FRENCH ).unwrap(),
+			"Yves"
 		);
+	}
+
+	#[test]
+	fn test_add_case_letter_polish() {
+		use unic_langid::langid;
+
+		const POLISH: LanguageIdentifier = langid!( "pl-PL" );
 
 		assert_eq!(
-			name.designate( NameCombo::Title, GrammaticalCase::Nominative, &GERMAN ),
-			Err( NameError::MissingNameElement( "title".to_string() ) )
+			add_case_letter_gendered( "Kowalski", GrammaticalCase::Nominative, &POLISH, Some( Gender::Male ) ).unwrap(),
+			"Kowalski".to_string()
 		);
-
 		assert_eq!(
-			name.designate( NameCombo::Polite, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Herr".to_string()
+			add_case_letter_gendered( "Kowalski", GrammaticalCase::Genetive, &POLISH, Some( Gender::Male ) ).unwrap(),
+			"Kowalskiego".to_string()
 		);
-
 		assert_eq!(
-			name.designate( NameCombo::PoliteName, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Herr Thomas von Würzinger".to_string()
+			add_case_letter_gendered( "Kowalski", GrammaticalCase::Dative, &POLISH, Some( Gender::Male ) ).unwrap(),
+			"Kowalskiemu".to_string()
 		);
-
 		assert_eq!(
-			name.designate( NameCombo::PoliteFirstname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Herr Thomas".to_string()
+			add_case_letter_gendered( "Kowalski", GrammaticalCase::Accusative, &POLISH, Some( Gender::Male ) ).unwrap(),
+			"Kowalskiego".to_string()
 		);
-
 		assert_eq!(
-			name.designate( NameCombo::PoliteSurname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Herr von Würzinger".to_string()
+			add_case_letter_gendered( "Kowalska", GrammaticalCase::Genetive, &POLISH, Some( Gender::Female ) ).unwrap(),
+			"Kowalskiej".to_string()
 		);
-
 		assert_eq!(
-			name.designate( NameCombo::PoliteFullname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Herr Thomas Jakob von Würzinger".to_string()
+			add_case_letter_gendered( "Kowalska", GrammaticalCase::Dative, &POLISH, Some( Gender::Female ) ).unwrap(),
+			"Kowalskiej".to_string()
 		);
-
 		assert_eq!(
-			name.designate( NameCombo::Nickname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Würzi".to_string()
+			add_case_letter_gendered( "Kowalska", GrammaticalCase::Accusative, &POLISH, Some( Gender::Female ) ).unwrap(),
+			"Kowalską".to_string()
 		);
-
 		assert_eq!(
-			name.designate( NameCombo::FirstNickname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Thomas Würzi".to_string()
+			add_case_letter_gendered( "Kowalska", GrammaticalCase::Genetive, &POLISH, Some( Gender::Male ) ).unwrap(),
+			"Kowalska".to_string()
+		);
+		assert_eq!(
+			add_case_letter( "Zawadzki", GrammaticalCase::Genetive, &POLISH ).unwrap(),
+			"Zawadzkiego".to_string()
+		);
+		assert_eq!(
+			add_case_letter( "Nowak", GrammaticalCase::Genetive, &POLISH ).unwrap(),
+			"Nowak".to_string()
 		);
 
+		let name = Names::new()
+			.with_forenames( &[ "Anna" ] )
+			.with_surname( "Kowalska" )
+			.with_gender( Gender::Female );
+
 		assert_eq!(
-			name.designate( NameCombo::NickSurname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Würzi von Würzinger".to_string()
+			name.designate( NameCombo::Surname, GrammaticalCase::Genetive, &POLISH ).unwrap(),
+			"Kowalskiej".to_string()
 		);
+	}
+
+	#[test]
+	#[cfg( feature = "serde" )]
+	fn grammatical_case_serde() {
+		assert_eq!( serde_json::to_string( &GrammaticalCase::Genetive ).unwrap(), r#""genetive""#.to_string() );
+
+		for case in [ GrammaticalCase::Nominative, GrammaticalCase::Genetive, GrammaticalCase::Dative, GrammaticalCase::Accusative, GrammaticalCase::Vocative ] {
+			let json = serde_json::to_string( &case ).unwrap();
+			assert_eq!( serde_json::from_str::<GrammaticalCase>( &json ).unwrap(), case );
+			assert_eq!( GrammaticalCase::from_str( json.trim_matches( '"' ) ).unwrap(), case );
+		}
+	}
+
+	#[test]
+	fn name_combo_from_str() {
+		assert_eq!( NameCombo::from_str( "Name" ).unwrap(), NameCombo::Name );
+		assert_eq!( NameCombo::from_str( "PoliteTitleName" ).unwrap(), NameCombo::PoliteTitleName );
+		assert_eq!( NameCombo::from_str( "SurnameFirst" ).unwrap(), NameCombo::SurnameFirst );
+		assert_eq!( NameCombo::from_str( "PoliteRankSurname" ).unwrap(), NameCombo::PoliteRankSurname );
+		assert_eq!( NameCombo::from_str( "RankOnly" ).unwrap(), NameCombo::Rank );
+	}
+
+	#[test]
+	fn name_combo_all() {
+		assert_eq!( NameCombo::ALL.len(), 63 );
+
+		for combo in NameCombo::ALL {
+			assert_eq!( NameCombo::from_str( &format!( "{:?}", combo ) ), Ok( *combo ) );
+		}
+	}
+
+	#[test]
+	#[cfg( feature = "serde" )]
+	fn name_combo_serde() {
+		assert_eq!( serde_json::to_string( &NameCombo::PoliteTitleName ).unwrap(), r#""PoliteTitleName""#.to_string() );
+
+		for combo in NameCombo::ALL {
+			let json = serde_json::to_string( combo ).unwrap();
+			assert_eq!( serde_json::from_str::<NameCombo>( &json ).unwrap(), *combo );
+			assert_eq!( NameCombo::from_str( json.trim_matches( '"' ) ).unwrap(), *combo );
+		}
+	}
+
+	#[test]
+	fn name_combo_as_str() {
+		assert_eq!( NameCombo::PoliteTitleName.as_str(), "PoliteTitleName" );
+		assert_eq!( NameCombo::PoliteTitleName.to_string(), "PoliteTitleName".to_string() );
+
+		for combo in NameCombo::ALL {
+			assert_eq!( NameCombo::from_str( combo.as_str() ), Ok( *combo ) );
+			assert_eq!( NameCombo::from_str( &combo.to_string() ), Ok( *combo ) );
+		}
+	}
+
+	#[test]
+	fn test_initials() {
+		assert_eq!( initials( "Test Test" ), "T. T.".to_string() );
+		assert_eq!( initials( "Thomas von Würzinger" ), "T. v. W.".to_string() );
+		assert_eq!( initials( "Müller-Lüdenscheidt" ), "M.-L.".to_string() );
+		assert_eq!( initials( "Hans-Peter Müller-Lüdenscheidt" ), "H.-P. M.-L.".to_string() );
+	}
+
+	#[test]
+	fn test_initials_uppercase() {
+		assert_eq!( initials( "éric" ), "É.".to_string() );
+		assert_eq!( initials( "ßandra" ), "SS.".to_string() );
+		assert_eq!( initials( "jean de la fontaine" ), "J. d. l. F.".to_string() );
+	}
+
+	#[test]
+	fn test_nickname_styled() {
+		assert_eq!( nickname_styled( "Jack", NicknameStyle::Bare ), "Jack".to_string() );
+		assert_eq!( nickname_styled( "Jack", NicknameStyle::DoubleQuoted ), "\"Jack\"".to_string() );
+		assert_eq!( nickname_styled( "Jack", NicknameStyle::Parenthesized ), "(Jack)".to_string() );
+		assert_eq!( NicknameStyle::default(), NicknameStyle::Bare );
+	}
+
+	#[test]
+	fn test_fold_diacritics() {
+		assert_eq!( fold_diacritics( "Würzinger" ), "Wuerzinger".to_string() );
+		assert_eq!( fold_diacritics( "Strauß" ), "Strauss".to_string() );
+		assert_eq!( fold_diacritics( "Élodie Françoise" ), "Elodie Francoise".to_string() );
+	}
+
+	#[test]
+	fn create_names() {
+		assert_eq!( Names::new(), Names::default() );
+		assert_eq!( Names::new()
+			.with_forenames( &[ "Test1", "Test2" ] ), Names {
+				forenames: vec![ "Test1".to_string(), "Test2".to_string() ],
+				..Default::default()
+			}
+		);
+		assert_eq!( Names::new()
+			.with_forenames_iter( vec![ "Test1".to_string(), "Test2".to_string() ] ), Names {
+				forenames: vec![ "Test1".to_string(), "Test2".to_string() ],
+				..Default::default()
+			}
+		);
+		assert_eq!( Names::new()
+			.with_forenames_iter( [ "Test1", "Test2" ] ), Names {
+				forenames: vec![ "Test1".to_string(), "Test2".to_string() ],
+				..Default::default()
+			}
+		);
+		assert_eq!( Names::new()
+			.with_forename( "Thomas" )
+			.with_forename( "Jakob" ), Names {
+				forenames: vec![ "Thomas".to_string(), "Jakob".to_string() ],
+				..Default::default()
+			}
+		);
+		assert_eq!( Names::new()
+			.with_patronymic( "Test" ), Names {
+				patronymic: Some( "Test".to_string() ),
+				..Default::default()
+			}
+		);
+		assert_eq!( Names::new()
+			.with_predicate( "Test" ), Names {
+				predicate: Some( "Test".to_string() ),
+				..Default::default()
+			}
+		);
+		assert_eq!( Names::new()
+			.with_surname( "Test" ), Names {
+				surname: Some( "Test".to_string() ),
+				..Default::default()
+			}
+		);
+		assert_eq!( Names::new()
+			.with_second_surname( "Test" ), Names {
+				surname_second: Some( "Test".to_string() ),
+				..Default::default()
+			}
+		);
+		assert_eq!( Names::new()
+			.with_suffix( "Test" ), Names {
+				suffix: Some( "Test".to_string() ),
+				..Default::default()
+			}
+		);
+		assert_eq!( Names::new()
+			.with_birthname( "Test" ), Names {
+				birthname: Some( "Test".to_string() ),
+				..Default::default()
+			}
+		);
+		assert_eq!( Names::new()
+			.with_title( "Test" ), Names {
+				titles: vec![ "Test".to_string() ],
+				..Default::default()
+			}
+		);
+		assert_eq!( Names::new()
+			.with_title( "Test1" )
+			.with_title( "Test2" ), Names {
+				titles: vec![ "Test1".to_string(), "Test2".to_string() ],
+				..Default::default()
+			}
+		);
+		assert_eq!( Names::new()
+			.with_title( "Test" )
+			.with_titles( &[ "Test1", "Test2" ] ), Names {
+				titles: vec![ "Test1".to_string(), "Test2".to_string() ],
+				..Default::default()
+			}
+		);
+		assert_eq!( Names::new()
+			.with_rank( "Test" ), Names {
+				rank: Some( "Test".to_string() ),
+				..Default::default()
+			}
+		);
+		assert_eq!( Names::new()
+			.with_rank_key( "Test" ), Names {
+				rank_key: Some( "Test".to_string() ),
+				..Default::default()
+			}
+		);
+		assert_eq!( Names::new()
+			.with_nickname( "Test" ), Names {
+				nickname: Some( "Test".to_string() ),
+				..Default::default()
+			}
+		);
+		assert_eq!( Names::new()
+			.with_honorname( "Test" ), Names {
+				honorname: Some( "Test".to_string() ),
+				..Default::default()
+			}
+		);
+		assert_eq!( Names::new()
+			.with_supername( "Test" ), Names {
+				supername: Some( "Test".to_string() ),
+				..Default::default()
+			}
+		);
+		assert_eq!( Names::new()
+			.with_gender( Gender::Female ), Names {
+				gender: Some( Gender::Female ),
+				..Default::default()
+			}
+		);
+		assert_eq!( Names::new()
+			.with_gender_str( "female" ).unwrap(), Names {
+				gender: Some( Gender::Female ),
+				..Default::default()
+			}
+		);
+		assert_eq!( Names::new()
+			.with_gender_str( "Male" ).unwrap(), Names {
+				gender: Some( Gender::Male ),
+				..Default::default()
+			}
+		);
+		assert!( Names::new().with_gender_str( "unknown" ).is_err() );
+	}
+
+	#[test]
+	fn names_empty_elements() {
+		let name = Names::new()
+			.with_forenames( &[ "Penelope", "", " Karin " ] )
+			.with_forename( "  " )
+			.with_surname( "  " )
+			.with_predicate( "" )
+			.with_title( " " );
+
+		assert_eq!( name.forenames(), &vec![ "Penelope".to_string(), "Karin".to_string() ] );
+		assert!( !name.has_element( "surname" ) );
+		assert!( !name.has_element( "predicate" ) );
+		assert!( !name.has_element( "title" ) );
+
+		assert!( Names::new().with_surname( "  " ).is_empty() );
+		assert_eq!( Names::new().with_surname( " Würzinger " ), Names::new().with_surname( "Würzinger" ) );
+	}
+
+	#[test]
+	fn names_surname_parts() {
+		let name = Names::new().with_surname_parts( "von der Leyen" );
+		assert_eq!( name, Names::new().with_predicate( "von der" ).with_surname( "Leyen" ) );
+
+		let name = Names::new().with_surname_parts( "Würzinger" );
+		assert_eq!( name, Names::new().with_surname( "Würzinger" ) );
+
+		// Capitalized particles are part of the surname.
+		let name = Names::new().with_surname_parts( "Van Halen" );
+		assert_eq!( name, Names::new().with_surname( "Van Halen" ) );
+
+		let name = Names::new().with_surname_parts( "de la Cruz Pérez" );
+		assert_eq!( name, Names::new().with_predicate( "de la" ).with_surname( "Cruz Pérez" ) );
+
+		let name = Names::new().with_surname_parts( "van" );
+		assert_eq!( name, Names::new().with_surname( "van" ) );
+	}
+
+	#[test]
+	fn names_getters() {
+		let name = Names::new()
+			.with_forenames( &[ "Penelope", "Karin" ] )
+			.with_patronymic( "Ivanovna" )
+			.with_predicate( "von" )
+			.with_surname( "Würzinger" )
+			.with_second_surname( "Márquez" )
+			.with_suffix( "Jr." )
+			.with_birthname( "Stauff" )
+			.with_titles( &[ "Prof.", "Dr." ] )
+			.with_rank( "Majorin" )
+			.with_rank_key( "rank-major" )
+			.with_nickname( "Würzi" )
+			.with_honorname( "Große" )
+			.with_supername( "Wurz" );
+
+		assert_eq!( name.patronymic(), Some( "Ivanovna" ) );
+		assert_eq!( name.predicate(), Some( "von" ) );
+		assert_eq!( name.surname(), Some( "Würzinger" ) );
+		assert_eq!( name.second_surname(), Some( "Márquez" ) );
+		assert_eq!( name.suffix(), Some( "Jr." ) );
+		assert_eq!( name.birthname(), Some( "Stauff" ) );
+		assert_eq!( name.title(), Some( "Prof." ) );
+		assert_eq!( name.titles(), &vec![ "Prof.".to_string(), "Dr.".to_string() ] );
+		assert_eq!( name.rank(), Some( "Majorin" ) );
+		assert_eq!( name.rank_key(), Some( "rank-major" ) );
+		assert_eq!( name.nickname(), Some( "Würzi" ) );
+		assert_eq!( name.honorname(), Some( "Große" ) );
+		assert_eq!( name.supername(), Some( "Wurz" ) );
+
+		let name = Names::new();
+		assert_eq!( name.predicate(), None );
+		assert_eq!( name.title(), None );
+		assert_eq!( name.rank(), None );
+	}
+
+	#[test]
+	fn names_setters() {
+		struct Person {
+			name: Names,
+		}
+
+		let mut person = Person {
+			name: Names::new()
+				.with_forenames( &[ "Penelope" ] )
+				.with_surname( "Würzinger" )
+				.with_rank( "Hauptmann" ),
+		};
+
+		let name = &mut person.name;
+		name.set_rank( "Majorin" );
+		name.set_title( "Dr." );
+		name.set_gender( Some( Gender::Female ) );
+		name.set_surname( "Stauff" );
+
+		assert_eq!( person.name, Names::new()
+			.with_forenames( &[ "Penelope" ] )
+			.with_surname( "Stauff" )
+			.with_rank( "Majorin" )
+			.with_title( "Dr." )
+			.with_gender( Gender::Female )
+		);
+
+		person.name.set_rank( "" );
+		person.name.set_titles( &[] );
+		person.name.set_gender( None );
+
+		assert_eq!( person.name, Names::new()
+			.with_forenames( &[ "Penelope" ] )
+			.with_surname( "Stauff" )
+		);
+	}
+
+	#[test]
+	fn names_build() {
+		assert_eq!(
+			Names::builder()
+				.with_forenames( &[ "Penelope" ] )
+				.with_predicate( "von" )
+				.with_surname( "Würzinger" )
+				.with_birthname( "Stauff" )
+				.build(),
+			Ok( Names::new()
+				.with_forenames( &[ "Penelope" ] )
+				.with_predicate( "von" )
+				.with_surname( "Würzinger" )
+				.with_birthname( "Stauff" )
+			)
+		);
+		assert_eq!( Names::builder().build(), Ok( Names::new() ) );
+		assert_eq!(
+			Names::builder()
+				.with_forenames_iter( vec![ "Penelope".to_string() ] )
+				.with_surname_opt( Some( "Würzinger" ) )
+				.with_gender_str( "female" )
+				.unwrap()
+				.build(),
+			Ok( Names::new().with_forenames( &[ "Penelope" ] ).with_surname( "Würzinger" ).with_gender( Gender::Female ) )
+		);
+		assert!( matches!(
+			Names::builder().with_forenames( &[ "Penelope" ] ).with_rufname_index( 1 ).build(),
+			Err( NameError::NotExpressionable( _ ) )
+		) );
+
+		assert_eq!(
+			Names::builder()
+				.with_forenames( &[ "Penelope" ] )
+				.with_predicate( "von" )
+				.build(),
+			Err( NameError::MissingNameElement( "surname".to_string() ) )
+		);
+		assert_eq!(
+			Names::builder()
+				.with_birthname( "Stauff" )
+				.build(),
+			Err( NameError::MissingNameElement( "surname".to_string() ) )
+		);
+	}
+
+	#[test]
+	fn names_elements() {
+		let name = Names::new();
+		assert!( name.is_empty() );
+		assert!( Names::new().with_rufname_index( 1 ).is_empty() );
+		assert!( Names::new().with_rank_feminize( true ).with_surname_feminize( true ).is_empty() );
+		assert!( !Names::new().with_rank_key( "major" ).is_empty() );
+		assert!( !name.has_element( "forenames" ) );
+		assert!( !name.has_element( "surname" ) );
+
+		let name = Names::new().with_forenames( &[ "Penelope", "Karin" ] );
+		assert!( !name.is_empty() );
+		assert!( name.has_element( "forenames" ) );
+		assert!( !name.has_element( "surname" ) );
+		assert!( !name.has_element( "title" ) );
+		assert!( !name.has_element( "gender" ) );
+		assert!( !name.has_element( "unknown" ) );
+	}
+
+	#[test]
+	fn names_merge() {
+		let hr = Names::new()
+			.with_title( "Dr." )
+			.with_rank( "Majorin" )
+			.with_gender( Gender::Undefined );
+		let directory = Names::new()
+			.with_forenames( &[ "Penelope", "Karin" ] )
+			.with_predicate( "von" )
+			.with_surname( "Würzinger" )
+			.with_title( "Prof." )
+			.with_gender( Gender::Female );
+
+		assert_eq!( hr.clone().merge( directory.clone() ), Names::new()
+			.with_forenames( &[ "Penelope", "Karin" ] )
+			.with_predicate( "von" )
+			.with_surname( "Würzinger" )
+			.with_title( "Dr." )
+			.with_rank( "Majorin" )
+			.with_gender( Gender::Female )
+		);
+		assert_eq!( directory.clone().merge( hr ), directory.with_rank( "Majorin" ) );
+		assert_eq!(
+			Names::new().with_gender( Gender::Undefined ).merge( Names::new() ),
+			Names::new().with_gender( Gender::Undefined )
+		);
+	}
+
+	#[test]
+	fn names_can_designate() {
+		use unic_langid::langid;
+
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+
+		let name = Names::new()
+			.with_forenames( &[ "Penelope", "Karin" ] )
+			.with_surname( "Würzinger" )
+			.with_gender( Gender::Female );
+
+		assert!( name.can_designate( NameCombo::Name ) );
+		assert!( name.can_designate( NameCombo::PoliteName ) );
+		assert!( name.can_designate( NameCombo::OrderedTitleName ) );
+		assert!( !name.can_designate( NameCombo::PoliteTitleName ) );
+		assert!( !name.can_designate( NameCombo::Nickname ) );
+		assert!( !Names::new().can_designate( NameCombo::Firstname ) );
+
+		// Keep in sync with the requirements of `designate()`.
+		let names_full = Names::new()
+			.with_forenames( &[ "Penelope", "Karin" ] )
+			.with_patronymic( "Ivanovna" )
+			.with_predicate( "von" )
+			.with_surname( "Würzinger" )
+			.with_title( "Dr." )
+			.with_rank( "Majorin" )
+			.with_nickname( "Würzli" )
+			.with_honorname( "Große" )
+			.with_supername( "Würzt-das-Essen" )
+			.with_gender( Gender::Female );
+		for item in [ Names::new(), name, names_full ] {
+			for combo in NameCombo::ALL {
+				assert_eq!(
+					item.can_designate( *combo ),
+					item.designate( *combo, GrammaticalCase::Nominative, &GERMAN ).is_ok(),
+					"{}", combo
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn name_strings_male() {
+		use unic_langid::langid;
+
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+
+		// Thomas Jakob von Würzinger
+		let name = Names {
+			forenames: [ "Thomas", "Jakob" ].iter().map( |x| x.to_string() ).collect(),
+			patronymic: None,
+			predicate: Some( "von".to_string() ),
+			surname: Some( "Würzinger".to_string() ),
+			surname_second: None,
+			suffix: None,
+			birthname: None,
+			titles: Vec::new(),
+			rank: Some( "Hauptkommissar".to_string() ),
+			rank_key: None,
+			nickname: Some( "Würzi".to_string() ),
+			honorname: Some( "Dunkle".to_string() ),
+			supername: Some( "Würzt-das-Essen".to_string() ),
+			regnal: None,
+			gender: Some( Gender::Male ),
+			rank_feminize: false,
+			surname_feminize: false,
+			rufname_index: None,
+		};
+
+		assert_eq!(
+			name.designate( NameCombo::Name, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Thomas von Würzinger".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::Name, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"Thomas von Würzingers".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::Name, GrammaticalCase::Accusative, &GERMAN ).unwrap(),
+			"Thomas von Würzinger".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::Surname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"von Würzinger".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::Firstname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Thomas".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::Firstname, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"Thomas'".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::Forenames, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Thomas Jakob".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::Fullname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Thomas Jakob von Würzinger".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::Title, GrammaticalCase::Nominative, &GERMAN ),
+			Err( NameError::CannotExpress { combo: NameCombo::Title, missing: "title".to_string() } )
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::PoliteTitleSurname, GrammaticalCase::Nominative, &GERMAN ),
+			Err( NameError::CannotExpress { combo: NameCombo::PoliteTitleSurname, missing: "title".to_string() } )
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::Polite, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Herr".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::PoliteName, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Herr Thomas von Würzinger".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::PoliteFirstname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Herr Thomas".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::PoliteSurname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Herr von Würzinger".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::PoliteFullname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Herr Thomas Jakob von Würzinger".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::Nickname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Würzi".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::FirstNickname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Thomas Würzi".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::FirstNicknameQuoted, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Thomas \"Würzi\"".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::FirstNicknameParenthesized, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Thomas (Würzi)".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::NickSurname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Würzi von Würzinger".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::SurnameNickname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"von Würzinger 'Würzi'".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::SurnameNickname, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"von Würzingers 'Würzi'".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::Nickname, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"Würzis".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::FirstNickname, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"Thomas Würzis".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::FirstNicknameParenthesized, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"Thomas' (Würzi)".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::NickSurname, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"Würzi von Würzingers".to_string()
+		);
+
+		assert_eq!(
+			Names { nickname: None, ..name.clone() }.designate( NameCombo::SurnameNickname, GrammaticalCase::Nominative, &GERMAN ),
+			Err( NameError::CannotExpress { combo: NameCombo::SurnameNickname, missing: "nickname".to_string() } )
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::Supername, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Würzt-das-Essen".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::FirstSupername, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Thomas Würzt-das-Essen".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::SuperName, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Thomas Würzt-das-Essen von Würzinger".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::PoliteSupername, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Herr Würzt-das-Essen".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::SupernameSurname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Würzt-das-Essen von Würzinger".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::SupernameSurname, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"Würzt-das-Essen von Würzingers".to_string()
+		);
+
+		assert_eq!(
+			Names { surname: None, predicate: None, ..name.clone() }.designate( NameCombo::SupernameSurname, GrammaticalCase::Nominative, &GERMAN ),
+			Err( NameError::CannotExpress { combo: NameCombo::SupernameSurname, missing: "surname".to_string() } )
+		);
+
+		assert_eq!(
+			Names { supername: None, ..name.clone() }.designate( NameCombo::SupernameSurname, GrammaticalCase::Nominative, &GERMAN ),
+			Err( NameError::CannotExpress { combo: NameCombo::SupernameSurname, missing: "supername".to_string() } )
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::RankSupername, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Hauptkommissar Würzt-das-Essen".to_string()
+		);
+	}
+
+	#[test]
+	fn name_strings_female() {
+		use unic_langid::langid;
+
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+
+		// Penelope Karin von Würzinger geb. Stauff
+		let name = Names {
+			forenames: [ "Penelope", "Karin" ].iter().map( |x| x.to_string() ).collect(),
+			patronymic: None,
+			predicate: Some( "von".to_string() ),
+			surname: Some( "Würzinger".to_string() ),
+			surname_second: None,
+			suffix: None,
+			birthname: Some( "Stauff".to_string() ),
+			titles: vec![ "Dr.".to_string() ],
+			rank: Some( "Majorin".to_string() ),
+			rank_key: None,
+			nickname: None,
+			honorname: Some( "Große".to_string() ),
+			supername: None,
+			regnal: None,
+			gender: Some( Gender::Female ),
+			rank_feminize: false,
+			surname_feminize: false,
+			rufname_index: None,
+		};
+
+		assert_eq!(
+			name.designate( NameCombo::Name, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Penelope von Würzinger".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::Name, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"Penelope von Würzingers".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::Name, GrammaticalCase::Accusative, &GERMAN ).unwrap(),
+			"Penelope von Würzinger".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::Surname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"von Würzinger".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::Firstname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Penelope".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::Fullname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Penelope Karin von Würzinger geb. Stauff".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::Fullname, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"Penelope Karin von Würzingers geb. Stauff".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::Title, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Dr.".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::TitleName, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Dr. Penelope von Würzinger".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::TitleFirstname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Dr. Penelope".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::TitleSurname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Dr. von Würzinger".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::TitleFullname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Dr. Penelope Karin von Würzinger geb. Stauff".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::Polite, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Frau".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::PoliteName, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Frau Penelope von Würzinger".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::PoliteFirstname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Frau Penelope".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::PoliteSurname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Frau von Würzinger".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::PoliteFullname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Frau Penelope Karin von Würzinger geb. Stauff".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::PoliteTitleName, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Frau Dr. Penelope von Würzinger".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::PoliteTitleSurname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Frau Dr. von Würzinger".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::PoliteTitleFullname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Frau Dr. Penelope Karin von Würzinger geb. Stauff".to_string()
+		);
+
+		assert_eq!(
+			Names { gender: None, ..name.clone() }.designate( NameCombo::PoliteTitleFullname, GrammaticalCase::Nominative, &GERMAN ),
+			Err( NameError::CannotExpress { combo: NameCombo::PoliteTitleFullname, missing: "gender".to_string() } )
+		);
+
+		assert_eq!(
+			Names { titles: Vec::new(), ..name.clone() }.designate( NameCombo::PoliteTitleFullname, GrammaticalCase::Nominative, &GERMAN ),
+			Err( NameError::CannotExpress { combo: NameCombo::PoliteTitleFullname, missing: "title".to_string() } )
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::Rank, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Majorin".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::PoliteRank, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Frau Majorin".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::PoliteRankSurname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Frau Majorin von Würzinger".to_string()
+		);
+
+		assert_eq!(
+			Names { gender: None, ..name.clone() }.designate( NameCombo::PoliteRankSurname, GrammaticalCase::Nominative, &GERMAN ),
+			Err( NameError::CannotExpress { combo: NameCombo::PoliteRankSurname, missing: "gender".to_string() } )
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::RankName, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Majorin Penelope von Würzinger".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::RankFirstname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Majorin Penelope".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::RankSurname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Majorin von Würzinger".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::RankFullname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Majorin Penelope Karin von Würzinger geb. Stauff".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::RankTitleName, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Majorin Dr. Penelope von Würzinger".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::TitleRankName, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Dr. Majorin Penelope von Würzinger".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::Honor, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Große".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::Honortitle, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Die Große".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::FirstHonorname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Penelope die Große".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::OrderedName, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Würzinger, Penelope von".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::OrderedNameLibrary, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"von Würzinger, Penelope".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::OrderedSurname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Würzinger, von".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::OrderedTitleName, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Würzinger, Dr. Penelope von".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::Initials, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"P. v. W.".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::InitialsNoParticle, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"P. W.".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::InitialsFull, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Dr. P. K. v. W.".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::TitleInitialsShort, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Dr. P. v. W.".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::ForenameInitials, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"P. K.".to_string()
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::AnonymizedSurname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Penelope v. W.".to_string()
+		);
+
+		assert_eq!(
+			Names::new().with_surname( "Würzinger" ).designate( NameCombo::ForenameInitials, GrammaticalCase::Nominative, &GERMAN ),
+			Err( NameError::CannotExpress { combo: NameCombo::ForenameInitials, missing: "forenames".to_string() } )
+		);
+
+		assert_eq!(
+			name.designate( NameCombo::Sign, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Dr. P. K. v. Würzinger".to_string()
+		);
+	}
+
+	#[test]
+	fn name_strings_multiple_titles() {
+		use unic_langid::langid;
+
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+
+		let name = Names::new()
+			.with_forenames( &[ "Penelope", "Karin" ] )
+			.with_predicate( "von" )
+			.with_surname( "Würzinger" )
+			.with_titles( &[ "Prof.", "Dr." ] );
+
+		assert_eq!(
+			name.designate( NameCombo::Title, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Prof. Dr.".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::TitleName, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Prof. Dr. Penelope von Würzinger".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::OrderedTitleName, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Würzinger, Prof. Dr. Penelope von".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::InitialsFull, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Prof. Dr. P. K. v. W.".to_string()
+		);
+	}
+
+	#[test]
+	#[cfg( feature = "serde" )]
+	fn names_deserialize_titles() {
+		let name: Names = serde_json::from_str( r#"{ "title": "Dr." }"# ).unwrap();
+		assert_eq!( name, Names::new().with_title( "Dr." ) );
+
+		let name: Names = serde_json::from_str( r#"{ "titles": [ "Prof.", "Dr." ] }"# ).unwrap();
+		assert_eq!( name, Names::new().with_titles( &[ "Prof.", "Dr." ] ) );
+
+		let name: Names = serde_json::from_str( r#"{ "title": null }"# ).unwrap();
+		assert_eq!( name, Names::new() );
+
+		let name = Names::new().with_titles( &[ "Prof.", "Dr." ] );
+		let json = serde_json::to_string( &name ).unwrap();
+		assert_eq!( serde_json::from_str::<Names>( &json ).unwrap(), name );
+	}
+
+	#[test]
+	#[cfg( feature = "serde" )]
+	fn names_serde_camel_case() {
+		let name = Names::new()
+			.with_surname( "García" )
+			.with_second_surname( "Márquez" )
+			.with_rank_key( "major" )
+			.with_rank_feminize( true );
+		let json = serde_json::to_value( &name ).unwrap();
+		assert_eq!( json["surnameSecond"], "Márquez" );
+		assert_eq!( json["rankKey"], "major" );
+		assert_eq!( json["rankFeminize"], true );
+		assert!( json.get( "surname_second" ).is_none() );
+		assert!( json.get( "rank_key" ).is_none() );
+		assert_eq!( serde_json::from_value::<Names>( json ).unwrap(), name );
+
+		let name: Names = serde_json::from_str( r#"{ "surname": "García", "surname_second": "Márquez" }"# ).unwrap();
+		assert_eq!( name, Names::new().with_surname( "García" ).with_second_surname( "Márquez" ) );
+	}
+
+	#[test]
+	fn name_strings_suffix() {
+		use unic_langid::langid;
+
+		const US_ENGLISH: LanguageIdentifier = langid!( "en-US" );
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+
+		// John Fitzgerald Kennedy Jr.
+		let name = Names::new()
+			.with_forenames( &[ "John", "Fitzgerald" ] )
+			.with_surname( "Kennedy" )
+			.with_suffix( "Jr." );
+
+		assert_eq!(
+			name.designate( NameCombo::Name, GrammaticalCase::Nominative, &US_ENGLISH ).unwrap(),
+			"John Kennedy Jr.".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::Name, GrammaticalCase::Genetive, &US_ENGLISH ).unwrap(),
+			"John Kennedy Jr.'s".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::Name, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"John Kennedys Jr.".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::Fullname, GrammaticalCase::Nominative, &US_ENGLISH ).unwrap(),
+			"John Fitzgerald Kennedy Jr.".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::Fullname, GrammaticalCase::Genetive, &US_ENGLISH ).unwrap(),
+			"John Fitzgerald Kennedy Jr.'s".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::Fullname, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"John Fitzgerald Kennedys Jr.".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::OrderedName, GrammaticalCase::Nominative, &US_ENGLISH ).unwrap(),
+			"Kennedy, John, Jr.".to_string()
+		);
+		assert_eq!(
+			name.clone().with_title( "Dr." ).designate( NameCombo::TitleName, GrammaticalCase::Nominative, &US_ENGLISH ).unwrap(),
+			"Dr. John Kennedy Jr.".to_string()
+		);
+	}
+
+	#[test]
+	fn name_strings_birthname() {
+		use unic_langid::langid;
+
+		const US_ENGLISH: LanguageIdentifier = langid!( "en-US" );
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+		const JAPANESE: LanguageIdentifier = langid!( "ja-JP" );
+
+		let name = Names::new()
+			.with_forenames( &[ "Penelope" ] )
+			.with_surname( "Würzinger" )
+			.with_birthname( "Stauff" );
+
+		assert_eq!(
+			name.designate( NameCombo::Fullname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Penelope Würzinger geb. Stauff".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::Fullname, GrammaticalCase::Nominative, &US_ENGLISH ).unwrap(),
+			"Penelope Würzinger born Stauff".to_string()
+		);
+		assert_eq!(
+			name.clone().with_gender( Gender::Female ).designate( NameCombo::Fullname, GrammaticalCase::Nominative, &US_ENGLISH ).unwrap(),
+			"Penelope Würzinger née Stauff".to_string()
+		);
+		assert_eq!(
+			name.clone().with_gender( Gender::Neutral ).designate( NameCombo::Fullname, GrammaticalCase::Nominative, &US_ENGLISH ).unwrap(),
+			"Penelope Würzinger born Stauff".to_string()
+		);
+		assert_eq!(
+			Names::new().with_forenames( &[ "John" ] ).with_surname( "Smith" ).with_birthname( "Jones" ).with_gender( Gender::Male )
+				.designate( NameCombo::Fullname, GrammaticalCase::Nominative, &US_ENGLISH ).unwrap(),
+			"John Smith né Jones".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::Fullname, GrammaticalCase::Nominative, &JAPANESE ),
+			Err( NameError::LangNotSupported( "ja-JP".to_string() ) )
+		);
+		assert_eq!(
+			name.designate( NameCombo::Fullname, GrammaticalCase::Nominative, &langid!( "fr-FR" ) ),
+			Err( NameError::LangNotSupported( "fr-FR".to_string() ) )
+		);
+	}
+
+	#[test]
+	fn name_strings_surname_first() {
+		use unic_langid::langid;
+
+		const US_ENGLISH: LanguageIdentifier = langid!( "en-US" );
+
+		// Yamada Tarou
+		let name = Names::new()
+			.with_forenames( &[ "Tarou" ] )
+			.with_surname( "Yamada" );
+
+		assert_eq!(
+			name.designate( NameCombo::SurnameFirst, GrammaticalCase::Nominative, &US_ENGLISH ).unwrap(),
+			"Yamada Tarou".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::SurnameFirst, GrammaticalCase::Genetive, &US_ENGLISH ).unwrap(),
+			"Yamada Tarou".to_string()
+		);
+		assert_eq!(
+			Names::new().with_surname( "Yamada" ).designate( NameCombo::SurnameFirst, GrammaticalCase::Nominative, &US_ENGLISH ),
+			Err( NameError::CannotExpress { combo: NameCombo::SurnameFirst, missing: "forenames".to_string() } )
+		);
+	}
+
+	#[test]
+	fn name_strings_initials_compound() {
+		use unic_langid::langid;
+
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+
+		let name = Names::new()
+			.with_forenames( &[ "Karin", "Anna" ] )
+			.with_surname( "Müller-Lüdenscheidt" );
+
+		assert_eq!(
+			name.designate( NameCombo::Initials, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"K. M.-L.".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::InitialsFull, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"K. A. M.-L.".to_string()
+		);
+
+		let name = Names::new()
+			.with_forenames( &[ "Juan", "Carlos" ] )
+			.with_predicate( "de la" )
+			.with_surname( "Cruz" );
+
+		assert_eq!(
+			name.designate( NameCombo::Initials, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"J. de la C.".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::InitialsFull, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"J. C. de la C.".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::Sign, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"J. C. de la Cruz".to_string()
+		);
+	}
+
+	#[test]
+	fn name_strings_second_surname() {
+		use unic_langid::langid;
+
+		const SPANISH: LanguageIdentifier = langid!( "es-ES" );
+
+		// Gabriel José García Márquez
+		let name = Names::new()
+			.with_forenames( &[ "Gabriel", "José" ] )
+			.with_surname( "García" )
+			.with_second_surname( "Márquez" );
+
+		assert_eq!( name.surname_full(), Some( "García Márquez".to_string() ) );
+		assert_eq!(
+			name.designate( NameCombo::Name, GrammaticalCase::Nominative, &SPANISH ).unwrap(),
+			"Gabriel García Márquez".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::Fullname, GrammaticalCase::Nominative, &SPANISH ).unwrap(),
+			"Gabriel José García Márquez".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::OrderedName, GrammaticalCase::Nominative, &SPANISH ).unwrap(),
+			"García Márquez, Gabriel".to_string()
+		);
+		assert_eq!( name.sort_key(), "garcia marquez gabriel".to_string() );
+	}
+
+	#[test]
+	#[cfg( feature = "i18n" )]
+	fn name_strings_rank_localized() {
+		use unic_langid::langid;
+
+		const US_ENGLISH: LanguageIdentifier = langid!( "en-US" );
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+
+		let name = Names::new()
+			.with_forenames( &[ "Thomas" ] )
+			.with_surname( "Würzinger" )
+			.with_rank( "Captain" )
+			.with_rank_key( "rank-captain" );
+
+		assert_eq!(
+			name.designate( NameCombo::Rank, GrammaticalCase::Nominative, &US_ENGLISH ).unwrap(),
+			"Captain".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::Rank, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Hauptmann".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::RankName, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Hauptmann Thomas Würzinger".to_string()
+		);
+
+		let name = name.with_rank_key( "unknown-rank" );
+
+		assert_eq!(
+			name.designate( NameCombo::Rank, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Captain".to_string()
+		);
+	}
+
+	#[test]
+	fn name_strings_patronymic() {
+		use unic_langid::langid;
+
+		const RUSSIAN: LanguageIdentifier = langid!( "ru-RU" );
+
+		// Ivan Ivanovich Petrov
+		let name = Names::new()
+			.with_forenames( &[ "Ivan" ] )
+			.with_patronymic( "Ivanovich" )
+			.with_surname( "Petrov" );
+
+		assert_eq!(
+			name.designate( NameCombo::NamePatronymic, GrammaticalCase::Nominative, &RUSSIAN ).unwrap(),
+			"Ivan Ivanovich Petrov".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::NamePatronymic, GrammaticalCase::Genetive, &RUSSIAN ).unwrap(),
+			"Ivan Ivanovich Petrov".to_string()
+		);
+		assert_eq!(
+			Names::new()
+				.with_forenames( &[ "Ivan" ] )
+				.with_surname( "Petrov" )
+				.designate( NameCombo::NamePatronymic, GrammaticalCase::Nominative, &RUSSIAN ),
+			Err( NameError::CannotExpress { combo: NameCombo::NamePatronymic, missing: "patronymic".to_string() } )
+		);
+	}
+
+	#[test]
+	fn name_strings_swedish() {
+		use unic_langid::langid;
+
+		const SWEDISH: LanguageIdentifier = langid!( "sv-SE" );
+
+		let name = Names::new()
+			.with_forenames( &[ "Karin" ] )
+			.with_surname( "Andersson" )
+			.with_birthname( "Lindqvist" )
+			.with_gender( Gender::Female );
+
+		assert_eq!(
+			name.designate( NameCombo::Fullname, GrammaticalCase::Nominative, &SWEDISH ).unwrap(),
+			"Karin Andersson född Lindqvist".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::Name, GrammaticalCase::Genetive, &SWEDISH ).unwrap(),
+			"Karin Anderssons".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::PoliteSurname, GrammaticalCase::Nominative, &SWEDISH ).unwrap(),
+			"Fru Andersson".to_string()
+		);
+		assert_eq!(
+			name.with_gender( Gender::Male ).designate( NameCombo::PoliteSurname, GrammaticalCase::Nominative, &SWEDISH ).unwrap(),
+			"Herr Andersson".to_string()
+		);
+	}
+
+	#[test]
+	fn name_strings_dutch() {
+		use unic_langid::langid;
+
+		const DUTCH: LanguageIdentifier = langid!( "nl-NL" );
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+
+		// Jan van der Berg
+		let name = Names::new()
+			.with_forenames( &[ "Jan" ] )
+			.with_predicate( "van der" )
+			.with_surname( "Berg" )
+			.with_gender( Gender::Male );
+
+		assert_eq!(
+			name.designate( NameCombo::Name, GrammaticalCase::Nominative, &DUTCH ).unwrap(),
+			"Jan van der Berg".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::Surname, GrammaticalCase::Nominative, &DUTCH ).unwrap(),
+			"Van der Berg".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::PoliteSurname, GrammaticalCase::Nominative, &DUTCH ).unwrap(),
+			"meneer Van der Berg".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::OrderedSurname, GrammaticalCase::Nominative, &DUTCH ).unwrap(),
+			"Berg, van der".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::Surname, GrammaticalCase::Genetive, &DUTCH ).unwrap(),
+			"Van der Bergs".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::Surname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"van der Berg".to_string()
+		);
+	}
+
+	#[test]
+	fn name_strings_honor() {
+		use unic_langid::langid;
+
+		const US_ENGLISH: LanguageIdentifier = langid!( "en-US" );
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+
+		let name = Names::new()
+			.with_forenames( &[ "Penelope" ] )
+			.with_honorname( "Große" );
+
+		assert_eq!(
+			name.designate( NameCombo::Honortitle, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Die Große".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::FirstHonorname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Penelope die Große".to_string()
+		);
+		assert_eq!(
+			name.clone().with_gender( Gender::Undefined ).designate( NameCombo::FirstHonorname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Penelope die Große".to_string()
+		);
+		assert_eq!(
+			name.clone().with_gender( Gender::Neutral ).designate( NameCombo::FirstHonorname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Penelope das Große".to_string()
+		);
+
+		let name = Names::new()
+			.with_forenames( &[ "Alexander" ] )
+			.with_honorname( "Great" )
+			.with_gender( Gender::Male );
+
+		assert_eq!(
+			name.designate( NameCombo::Honortitle, GrammaticalCase::Nominative, &US_ENGLISH ).unwrap(),
+			"The Great".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::Honortitle, GrammaticalCase::Nominative, &langid!( "ja-JP" ) ),
+			Err( NameError::LangNotSupported( "ja-JP".to_string() ) )
+		);
+		assert_eq!(
+			name.designate( NameCombo::FirstHonorname, GrammaticalCase::Nominative, &US_ENGLISH ).unwrap(),
+			"Alexander the Great".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::Honortitle, GrammaticalCase::Genetive, &US_ENGLISH ).unwrap(),
+			"The Great's".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::FirstHonorname, GrammaticalCase::Genetive, &US_ENGLISH ).unwrap(),
+			"Alexander the Great's".to_string()
+		);
+		assert_eq!(
+			name.clone().with_gender( Gender::Other ).designate( NameCombo::FirstHonorname, GrammaticalCase::Nominative, &US_ENGLISH ).unwrap(),
+			"Alexander the Great".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::FirstHonorname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Alexander der Great".to_string()
+		);
+
+		let name = Names::new()
+			.with_forenames( &[ "Eleanor" ] )
+			.with_honorname( "of Aquitaine" )
+			.with_gender( Gender::Female );
+
+		assert_eq!(
+			name.designate( NameCombo::FirstHonorname, GrammaticalCase::Nominative, &US_ENGLISH ).unwrap(),
+			"Eleanor of Aquitaine".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::FirstHonorname, GrammaticalCase::Genetive, &US_ENGLISH ).unwrap(),
+			"Eleanor of Aquitaine's".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::Honortitle, GrammaticalCase::Nominative, &US_ENGLISH ).unwrap(),
+			"of Aquitaine".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::Honortitle, GrammaticalCase::Nominative, &langid!( "ja-JP" ) ),
+			Err( NameError::LangNotSupported( "ja-JP".to_string() ) )
+		);
+
+		let name = name.with_forenames( &[ "Elisabeth" ] ).with_honorname( "von Thüringen" );
+		assert_eq!(
+			name.designate( NameCombo::FirstHonorname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Elisabeth von Thüringen".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::FirstHonorname, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"Elisabeths von Thüringen".to_string()
+		);
+
+		// German cannot decline the article and the adjective of the honorific name.
+		let name = Names::new()
+			.with_forenames( &[ "Penelope" ] )
+			.with_honorname( "Große" )
+			.with_gender( Gender::Female );
+		assert!( matches!(
+			name.designate( NameCombo::FirstHonorname, GrammaticalCase::Genetive, &GERMAN ),
+			Err( NameError::NotExpressionable( _ ) )
+		) );
+		assert!( matches!(
+			name.designate( NameCombo::FirstHonorname, GrammaticalCase::Dative, &GERMAN ),
+			Err( NameError::NotExpressionable( _ ) )
+		) );
+	}
+
+	#[test]
+	fn name_strings_roman_male() {
+		use unic_langid::langid;
+
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+
+		// Gaius Julius Caesar
+		let name = Names {
+			forenames: vec![ "Gaius".to_string() ],
+			patronymic: None,
+			predicate: None,
+			surname: Some( "Julius".to_string() ),
+			surname_second: None,
+			suffix: None,
+			birthname: None,
+			titles: Vec::new(),
+			rank: None,
+			rank_key: None,
+			nickname: Some( "Caesar".to_string() ),
+			honorname: None,
+			supername: None,
+			regnal: None,
+			gender: None,
+			rank_feminize: false,
+			surname_feminize: false,
+			rufname_index: None,
+		};
+
+		assert_eq!(
+			name.designate( NameCombo::TriaNomina, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Gaius Julius Caesar".to_string()
+		);
+	}
+
+	#[test]
+	fn name_strings_roman_female() {
+		use unic_langid::langid;
+
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+
+		// Iunia Prima
+		let name = Names {
+			forenames: Vec::new(),
+			patronymic: None,
+			predicate: None,
+			surname: Some( "Iunia".to_string() ),
+			surname_second: None,
+			suffix: None,
+			birthname: None,
+			titles: Vec::new(),
+			rank: None,
+			rank_key: None,
+			nickname: Some( "Prima".to_string() ),
+			honorname: None,
+			supername: None,
+			regnal: None,
+			gender: None,
+			rank_feminize: false,
+			surname_feminize: false,
+			rufname_index: None,
+		};
+
+		assert_eq!(
+			name.designate( NameCombo::DuaNomina, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Iunia Prima".to_string()
+		);
+	}
+
+	#[test]
+	fn name_designate_cow() {
+		use unic_langid::langid;
+
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+
+		let name = Names::new()
+			.with_forenames( &[ "Penelope" ] )
+			.with_surname( "Würzinger" );
+
+		assert!( matches!(
+			name.designate_cow( NameCombo::Firstname, GrammaticalCase::Nominative, &GERMAN ),
+			Ok( Cow::Borrowed( "Penelope" ) )
+		) );
+		assert!( matches!(
+			name.designate_cow( NameCombo::Firstname, GrammaticalCase::Genetive, &GERMAN ),
+			Ok( Cow::Owned( _ ) )
+		) );
+		assert!( matches!(
+			name.designate_cow( NameCombo::Name, GrammaticalCase::Nominative, &GERMAN ),
+			Ok( Cow::Owned( _ ) )
+		) );
+		assert_eq!(
+			name.designate_cow( NameCombo::Firstname, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"Penelopes"
+		);
+	}
+
+	#[test]
+	fn name_strings_surname_first_locale() {
+		use unic_langid::langid;
+
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+		const HUNGARIAN: LanguageIdentifier = langid!( "hu-HU" );
+		const JAPANESE: LanguageIdentifier = langid!( "ja-JP" );
+
+		let name = Names::new()
+			.with_forenames( &[ "Péter", "János" ] )
+			.with_surname( "Nagy" );
+
+		assert_eq!(
+			name.designate( NameCombo::Name, GrammaticalCase::Nominative, &HUNGARIAN ).unwrap(),
+			"Nagy Péter".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::Fullname, GrammaticalCase::Nominative, &HUNGARIAN ).unwrap(),
+			"Nagy Péter János".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::Name, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Péter Nagy".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::Fullname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Péter János Nagy".to_string()
+		);
+
+		let name = Names::new()
+			.with_forenames( &[ "Taro" ] )
+			.with_surname( "Yamada" );
+
+		assert_eq!(
+			name.designate( NameCombo::Name, GrammaticalCase::Nominative, &JAPANESE ).unwrap(),
+			"Yamada Taro".to_string()
+		);
+	}
+
+	#[test]
+	fn name_strings_whitespace() {
+		use unic_langid::langid;
+
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+
+		let name = Names::new()
+			.with_forenames( &[ "Penelope  Karin" ] )
+			.with_predicate( "von" )
+			.with_surname( "Würzinger \t Stauff" );
+
+		assert_eq!(
+			name.designate( NameCombo::Name, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Penelope Karin von Würzinger Stauff".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::Surname, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"von Würzinger Stauffs".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::OrderedName, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Würzinger Stauff, Penelope Karin von".to_string()
+		);
+		assert_eq!(
+			name.designate_cow( NameCombo::Surname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"von Würzinger Stauff"
+		);
+	}
+
+	#[test]
+	fn name_error_combo() {
+		use unic_langid::langid;
+
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+
+		let name = Names::new()
+			.with_forenames( &[ "Penelope" ] )
+			.with_title( "Dr." );
+
+		let err = name.designate( NameCombo::TitleSurname, GrammaticalCase::Nominative, &GERMAN ).unwrap_err();
+		assert_eq!( err, NameError::CannotExpress { combo: NameCombo::TitleSurname, missing: "surname".to_string() } );
+		assert_eq!( err.to_string(), "Name combination `TitleSurname` cannot be expressed, name element missing: `surname`".to_string() );
+
+		assert_eq!(
+			name.designate( NameCombo::Nickname, GrammaticalCase::Nominative, &GERMAN ),
+			Err( NameError::CannotExpress { combo: NameCombo::Nickname, missing: "nickname".to_string() } )
+		);
+	}
+
+	#[test]
+	fn name_rendered_len() {
+		use unic_langid::langid;
+
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+
+		let name = Names::new()
+			.with_forenames( &[ "Penelope", "Karin" ] )
+			.with_predicate( "von" )
+			.with_surname( "Würzinger" );
+
+		assert_eq!( name.rendered_len( NameCombo::Name, &GERMAN ), Ok( 22 ) );
+		assert_eq!( name.rendered_len( NameCombo::Initials, &GERMAN ), Ok( "P. v. W.".chars().count() ) );
+		assert_eq!( name.rendered_len( NameCombo::Fullname, &GERMAN ), Ok( "Penelope Karin von Würzinger".chars().count() ) );
+		assert!( name.rendered_len( NameCombo::Initials, &GERMAN ).unwrap() < name.rendered_len( NameCombo::Fullname, &GERMAN ).unwrap() );
+		assert_eq!(
+			name.rendered_len( NameCombo::Nickname, &GERMAN ),
+			Err( NameError::CannotExpress { combo: NameCombo::Nickname, missing: "nickname".to_string() } )
+		);
+	}
+
+	#[test]
+	fn name_designate_with_fallback() {
+		use unic_langid::langid;
+
+		const AUSTRIAN: LanguageIdentifier = langid!( "de-AT" );
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+		const JAPANESE: LanguageIdentifier = langid!( "ja-JP" );
+
+		let name = Names::new()
+			.with_forenames( &[ "Penelope" ] )
+			.with_surname( "Würzinger" )
+			.with_gender( Gender::Female );
+
+		assert_eq!(
+			name.designate_with_fallback( NameCombo::PoliteName, GrammaticalCase::Genetive, &[ AUSTRIAN, GERMAN ] ).unwrap(),
+			"Frau Penelope Würzingers".to_string()
+		);
+		assert_eq!(
+			name.designate_with_fallback( NameCombo::PoliteName, GrammaticalCase::Genetive, &[ JAPANESE, GERMAN ] ).unwrap(),
+			"Frau Penelope Würzingers".to_string()
+		);
+		assert_eq!(
+			name.designate_with_fallback( NameCombo::PoliteName, GrammaticalCase::Genetive, &[ JAPANESE ] ),
+			Err( NameError::LangNotSupported( "ja-JP".to_string() ) )
+		);
+		assert_eq!(
+			name.designate_with_fallback( NameCombo::Nickname, GrammaticalCase::Nominative, &[ JAPANESE, GERMAN ] ),
+			Err( NameError::CannotExpress { combo: NameCombo::Nickname, missing: "nickname".to_string() } )
+		);
+		assert_eq!(
+			name.designate_with_fallback( NameCombo::Name, GrammaticalCase::Nominative, &[] ),
+			Err( NameError::LangNotSupported( "".to_string() ) )
+		);
+	}
+
+	#[test]
+	fn name_try_designate() {
+		use unic_langid::langid;
+
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+		const JAPANESE: LanguageIdentifier = langid!( "ja-JP" );
+
+		let name = Names::new()
+			.with_forenames( &[ "Penelope" ] )
+			.with_surname( "Würzinger" );
+
+		assert_eq!(
+			name.try_designate( NameCombo::Name, GrammaticalCase::Nominative, &GERMAN ),
+			Ok( Some( "Penelope Würzinger".to_string() ) )
+		);
+		assert_eq!(
+			name.try_designate( NameCombo::TitleName, GrammaticalCase::Nominative, &GERMAN ),
+			Ok( None )
+		);
+		assert_eq!(
+			name.try_designate( NameCombo::Name, GrammaticalCase::Genetive, &JAPANESE ),
+			Err( NameError::LangNotSupported( "ja-JP".to_string() ) )
+		);
+	}
+
+	#[test]
+	fn name_strings_title_case() {
+		use unic_langid::langid;
+
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+		const US_ENGLISH: LanguageIdentifier = langid!( "en-US" );
+
+		let name = Names::new()
+			.with_forenames( &[ "Thomas" ] )
+			.with_surname( "Würzinger" )
+			.with_title( "Doktor" )
+			.with_rank( "Hauptmann" )
+			.with_gender( Gender::Male );
+
+		assert_eq!(
+			name.designate( NameCombo::Title, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"Doktors".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::TitleSurname, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"Doktor Würzingers".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::RankName, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"Hauptmann Thomas Würzingers".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::Rank, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"Hauptmanns".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::PoliteTitleName, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"Herr Doktor Thomas Würzingers".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::RankTitleName, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"Hauptmann Doktor Thomas Würzingers".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::TitleSurname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Doktor Würzinger".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::TitleSurname, GrammaticalCase::Genetive, &US_ENGLISH ).unwrap(),
+			"Doktor Würzinger's".to_string()
+		);
+
+		// Abbreviated titles are not inflected.
+		assert_eq!(
+			name.clone().with_titles( &[ "Dr." ] ).designate( NameCombo::TitleSurname, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"Dr. Würzingers".to_string()
+		);
+
+		// Feminine nouns do not take a case letter.
+		assert_eq!(
+			name.with_rank( "Majorin" ).with_gender( Gender::Female ).designate( NameCombo::RankSurname, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"Majorin Würzingers".to_string()
+		);
+	}
+
+	#[test]
+	fn name_strings_missing_surname() {
+		use unic_langid::langid;
+
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+
+		let name = Names::new()
+			.with_forenames( &[ "Penelope" ] )
+			.with_title( "Dr." )
+			.with_rank( "Hauptmann" )
+			.with_gender( Gender::Female );
+
+		assert_eq!(
+			name.designate( NameCombo::TitleSurname, GrammaticalCase::Nominative, &GERMAN ),
+			Err( NameError::CannotExpress { combo: NameCombo::TitleSurname, missing: "surname".to_string() } )
+		);
+		assert_eq!(
+			name.designate( NameCombo::RankSurname, GrammaticalCase::Nominative, &GERMAN ),
+			Err( NameError::CannotExpress { combo: NameCombo::RankSurname, missing: "surname".to_string() } )
+		);
+		assert_eq!(
+			name.designate( NameCombo::PoliteSurname, GrammaticalCase::Nominative, &GERMAN ),
+			Err( NameError::CannotExpress { combo: NameCombo::PoliteSurname, missing: "surname".to_string() } )
+		);
+	}
+
+	#[test]
+	fn name_designate_all() {
+		use unic_langid::langid;
+
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+
+		let name = Names::new()
+			.with_forenames( &[ "Penelope", "Karin" ] )
+			.with_predicate( "von" )
+			.with_surname( "Würzinger" )
+			.with_birthname( "Stauff" )
+			.with_rank( "Majorin" )
+			.with_nickname( "Würzi" )
+			.with_honorname( "Große" )
+			.with_supername( "Wurz" )
+			.with_gender( Gender::Female );
+
+		let all = name.designate_all( GrammaticalCase::Nominative, &GERMAN );
+
+		assert!( all.len() > 30 );
+		assert!( all.contains( &( NameCombo::Name, "Penelope von Würzinger".to_string() ) ) );
+		assert!( all.iter().all( |( form, _ )| *form != NameCombo::Title ) );
+
+		assert!( Names::new().designate_all( GrammaticalCase::Nominative, &GERMAN ).is_empty() );
+	}
+
+	#[test]
+	fn name_moniker() {
+		use unic_langid::langid;
+
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+
+		assert_eq!( Names::new().moniker( GrammaticalCase::Nominative, &GERMAN ), Err( NameError::MissingNameElement( "forenames/surname/nickname/supername".to_string() ) ) );
+		assert_eq!(
+			Names::new()
+				.with_forenames( &[ "Penelope", "Karin" ] )
+				.moniker( GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Penelope".to_string()
+		);
+		assert_eq!(
+			Names::new()
+				.with_forenames( &[ "Penelope", "Karin" ] )
+				.with_surname( "Würzinger" )
+				.moniker( GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Penelope Karin Würzinger".to_string()
+		);
+		assert_eq!(
+			Names::new()
+				.with_forenames( &[ "Penelope", "Karin" ] )
+				.with_predicate( "von" )
+				.with_surname( "Würzinger" )
+				.moniker( GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Penelope Karin von Würzinger".to_string()
+		);
+		assert_eq!(
+			Names::new()
+				.with_nickname( "Würzli" )
+				.moniker( GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Würzli".to_string()
+		);
+		assert_eq!(
+			Names::new()
+				.with_nickname( "Würzli" )
+				.with_surname( "Würzinger" )
+				.moniker( GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Würzinger".to_string()
+		);
+	}
+
+	#[test]
+	fn name_sort_key() {
+		let penelope = Names::new()
+			.with_forenames( &[ "Penelope", "Karin" ] )
+			.with_predicate( "von" )
+			.with_surname( "Würzinger" );
+		let thomas = Names::new()
+			.with_forenames( &[ "Thomas" ] )
+			.with_surname( "Wurz" );
+		let anna = Names::new()
+			.with_forenames( &[ "Anna" ] )
+			.with_surname( "Weber" );
+
+		assert_eq!( penelope.sort_key(), "wuerzinger penelope".to_string() );
+
+		let mut names = vec![ penelope.clone(), thomas.clone(), anna.clone() ];
+		names.sort_by_key( |x| x.sort_key() );
+		assert_eq!( names, vec![ anna, penelope, thomas ] );
+	}
+
+	#[test]
+	fn name_ord() {
+		let penelope = Names::new()
+			.with_forenames( &[ "Penelope", "Karin" ] )
+			.with_predicate( "von" )
+			.with_surname( "Würzinger" );
+		let thomas = Names::new()
+			.with_forenames( &[ "Thomas" ] )
+			.with_surname( "Wurz" );
+		let anna = Names::new()
+			.with_forenames( &[ "Anna" ] )
+			.with_surname( "Weber" );
+		let anna_dr = anna.clone().with_title( "Dr." );
+
+		let mut names = vec![ thomas.clone(), penelope.clone(), anna_dr.clone(), anna.clone() ];
+		names.sort();
+		assert_eq!( names, vec![ anna.clone(), anna_dr.clone(), penelope.clone(), thomas.clone() ] );
+
+		assert!( penelope < thomas );
+		assert_ne!( anna.cmp( &anna_dr ), Ordering::Equal );
+		assert_eq!( anna.cmp( &anna.clone() ), Ordering::Equal );
+
+		let set: alloc::collections::BTreeSet<Names> = [ thomas.clone(), anna.clone(), thomas ].into_iter().collect();
+		assert_eq!( set.len(), 2 );
+	}
+
+	#[test]
+	fn name_moniker_with() {
+		use unic_langid::langid;
+
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+
+		let order = [
+			NameCombo::Nickname,
+			NameCombo::Firstname,
+			NameCombo::Surname,
+		];
+
+		let name = Names::new()
+			.with_forenames( &[ "Penelope", "Karin" ] )
+			.with_surname( "Würzinger" );
+
+		assert_eq!(
+			name.clone().with_nickname( "Würzli" ).moniker_with( &order, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Würzli".to_string()
+		);
+		assert_eq!(
+			name.moniker_with( &order, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Penelope".to_string()
+		);
+		assert_eq!(
+			Names::new().moniker_with( &order, GrammaticalCase::Nominative, &GERMAN ),
+			Err( NameError::MissingNameElement( "nickname/forenames/surname".to_string() ) )
+		);
+		assert_eq!(
+			name.moniker_with( &[], GrammaticalCase::Nominative, &GERMAN ),
+			Err( NameError::IllegalCombo )
+		);
+	}
+
+	#[test]
+	fn name_identity_key() {
+		let name = Names::new()
+			.with_forenames( &[ "Penelope", "Karin" ] )
+			.with_predicate( "von" )
+			.with_surname( "Würzinger" )
+			.with_birthname( "Stauff" );
+
+		assert_eq!( name.identity_key(), "penelope karin|von würzinger|stauff".to_string() );
+
+		let name_other = Names::new()
+			.with_forenames( &[ "PENELOPE", "Karin" ] )
+			.with_predicate( "von" )
+			.with_surname( "Würzinger" )
+			.with_birthname( "Stauff" )
+			.with_title( "Dr." )
+			.with_rank( "Majorin" )
+			.with_nickname( "Würzi" )
+			.with_gender( Gender::Female );
+
+		assert_eq!( name.identity_key(), name_other.identity_key() );
+		assert_ne!( name.identity_key(), Names::new().with_forenames( &[ "Penelope" ] ).with_surname( "Würzinger" ).identity_key() );
+		assert_eq!( Names::new().with_surname( "Würzinger" ).identity_key(), "|würzinger|".to_string() );
+	}
+
+	#[test]
+	fn name_display() {
+		assert_eq!( Names::new().to_string(), "<unnamed>".to_string() );
+		assert_eq!(
+			Names::new()
+				.with_forenames( &[ "Penelope", "Karin" ] )
+				.with_predicate( "von" )
+				.with_surname( "Würzinger" )
+				.to_string(),
+			"Penelope Karin von Würzinger".to_string()
+		);
+		assert_eq!(
+			format!( "{}", Names::new().with_nickname( "Würzli" ) ),
+			"Würzli".to_string()
+		);
+	}
+
+	#[test]
+	#[cfg( feature = "arbitrary" )]
+	fn names_arbitrary() {
+		use arbitrary::{Arbitrary, Unstructured};
+
+		let bytes: Vec<u8> = ( 0..4096_u32 ).map( |x| ( x.wrapping_mul( 2_654_435_761 ) >> 13 ) as u8 ).collect();
+		let mut data = Unstructured::new( &bytes );
+
+		let locales = [ langid!( "en-US" ), langid!( "de-DE" ), langid!( "nl-NL" ), langid!( "fr-FR" ) ];
+
+		while !data.is_empty() {
+			let Ok( names ) = Names::arbitrary( &mut data ) else { break };
+			let Ok( form ) = NameCombo::arbitrary( &mut data ) else { break };
+			let Ok( case ) = GrammaticalCase::arbitrary( &mut data ) else { break };
+
+			for locale in &locales {
+				let _ = names.designate( form, case, locale );
+			}
+		}
+	}
+
+	#[test]
+	fn names_try_from_str() {
+		assert_eq!(
+			Names::try_from( "Thomas Jakob von Würzinger" ),
+			Ok( Names::new()
+				.with_forenames( &[ "Thomas", "Jakob" ] )
+				.with_predicate( "von" )
+				.with_surname( "Würzinger" ) )
+		);
+		assert_eq!(
+			Names::try_from( "Hans-Peter von der Leyen" ).unwrap().designate( NameCombo::Name, GrammaticalCase::Nominative, &langid!( "de-DE" ) ).unwrap(),
+			"Hans-Peter von der Leyen".to_string()
+		);
+		assert_eq!( Names::try_from( "Thomas" ), Err( NameError::IllegalCombo ) );
+		assert_eq!( Names::try_from( "" ), Err( NameError::IllegalCombo ) );
+		assert_eq!( Names::try_from( "thomas Würzinger" ), Err( NameError::IllegalCombo ) );
+		assert_eq!( Names::try_from( "Thomas von Würzinger Müller" ), Err( NameError::IllegalCombo ) );
+		assert_eq!( Names::try_from( "Thomas Würzinger (Würzi)" ), Err( NameError::IllegalCombo ) );
+	}
+
+	#[test]
+	fn names_rank_feminize() {
+		use unic_langid::langid;
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+		const ENGLISH: LanguageIdentifier = langid!( "en-US" );
+
+		let name = Names::new()
+			.with_forenames( &[ "Penelope" ] )
+			.with_surname( "Würzinger" )
+			.with_rank( "Major" )
+			.with_gender( Gender::Female );
+		assert_eq!( name.designate( NameCombo::RankSurname, GrammaticalCase::Nominative, &GERMAN ).unwrap(), "Major Würzinger".to_string() );
+
+		let name = name.with_rank_feminize( true );
+		assert_eq!( name.designate( NameCombo::RankSurname, GrammaticalCase::Nominative, &GERMAN ).unwrap(), "Majorin Würzinger".to_string() );
+		assert_eq!( name.designate( NameCombo::RankSurname, GrammaticalCase::Nominative, &ENGLISH ).unwrap(), "Major Würzinger".to_string() );
+
+		let name = name.with_rank( "Majorin" );
+		assert_eq!( name.designate( NameCombo::RankSurname, GrammaticalCase::Nominative, &GERMAN ).unwrap(), "Majorin Würzinger".to_string() );
+
+		let name = name.with_rank( "Major" ).with_gender( Gender::Male );
+		assert_eq!( name.designate( NameCombo::RankSurname, GrammaticalCase::Nominative, &GERMAN ).unwrap(), "Major Würzinger".to_string() );
+	}
+
+	#[test]
+	fn names_from_iter() {
+		let name: Names = [ "Thomas", "Jakob" ].into_iter().collect();
+		assert_eq!( name.forenames(), &vec![ "Thomas".to_string(), "Jakob".to_string() ] );
+
+		let name: Names = vec![ "Penelope".to_string(), " ".to_string() ].into_iter().collect();
+		assert_eq!( name, Names::new().with_forenames( &[ "Penelope" ] ) );
+	}
+
+	#[test]
+	fn name_strings_nasab() {
+		use unic_langid::langid;
+		const ARABIC: LanguageIdentifier = langid!( "ar" );
+
+		let name = Names::new()
+			.with_forenames( &[ "Ali" ] )
+			.with_patronymic( "Hassan" )
+			.with_surname( "al-Farsi" )
+			.with_gender( Gender::Male );
+
+		assert_eq!(
+			name.designate( NameCombo::Nasab, GrammaticalCase::Nominative, &ARABIC ).unwrap(),
+			"Ali ibn Hassan al-Farsi".to_string()
+		);
+		assert_eq!(
+			name.designate( NameCombo::Nasab, GrammaticalCase::Genetive, &ARABIC ).unwrap(),
+			"Ali ibn Hassan al-Farsi".to_string()
+		);
+		assert_eq!(
+			name.clone().with_forenames( &[ "Fatima" ] ).with_gender( Gender::Female ).designate( NameCombo::Nasab, GrammaticalCase::Nominative, &ARABIC ).unwrap(),
+			"Fatima bint Hassan al-Farsi".to_string()
+		);
+		assert_eq!(
+			Names { gender: None, ..name.clone() }.designate( NameCombo::Nasab, GrammaticalCase::Nominative, &ARABIC ),
+			Err( NameError::CannotExpress { combo: NameCombo::Nasab, missing: "gender".to_string() } )
+		);
+		assert!( matches!(
+			name.with_gender( Gender::Neutral ).designate( NameCombo::Nasab, GrammaticalCase::Nominative, &ARABIC ),
+			Err( NameError::NotExpressionable( _ ) )
+		) );
+	}
+
+	#[test]
+	fn names_missing_for() {
+		let name = Names::new()
+			.with_forenames( &[ "Penelope" ] )
+			.with_surname( "Würzinger" );
+
+		assert_eq!( name.missing_for( NameCombo::PoliteTitleName ), vec![ "gender", "title" ] );
+		assert_eq!( name.missing_for( NameCombo::Name ), Vec::<&str>::new() );
+		assert_eq!( Names::new().missing_for( NameCombo::Name ), vec![ "forenames", "surname" ] );
+	}
+
+	#[test]
+	fn names_rename_marriage() {
+		use unic_langid::langid;
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+
+		let name = Names::new()
+			.with_forenames( &[ "Penelope" ] )
+			.with_surname( "Stauff" )
+			.rename_marriage( "Würzinger" );
+		assert_eq!(
+			name.designate( NameCombo::Fullname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Penelope Würzinger geb. Stauff".to_string()
+		);
+
+		// The birthname of an earlier marriage is kept.
+		let name = name.rename_marriage( "Müller" );
+		assert_eq!(
+			name.designate( NameCombo::Fullname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Penelope Müller geb. Stauff".to_string()
+		);
+	}
+
+	#[test]
+	fn names_compact() {
+		let name = Names::new()
+			.with_forenames( &[ "Penelope", "Karin" ] )
+			.with_rufname_index( 1 )
+			.with_patronymic( "Ivanovna" )
+			.with_predicate( "von" )
+			.with_surname( "Würzinger" )
+			.with_second_surname( "Pipe|Comma," )
+			.with_suffix( "Jr." )
+			.with_birthname( "Stauff" )
+			.with_titles( &[ "Prof.", "Dr.\\" ] )
+			.with_rank( "Majorin" )
+			.with_rank_key( "major" )
+			.with_rank_feminize( true )
+			.with_surname_feminize( true )
+			.with_nickname( "Würzli" )
+			.with_honorname( "Große" )
+			.with_supername( "Würzt-das-Essen" )
+			.with_regnal( "II" )
+			.with_gender( Gender::Female );
+
+		assert_eq!( Names::from_compact( &name.to_compact() ), Ok( name ) );
+		assert_eq!( Names::from_compact( &Names::new().to_compact() ), Ok( Names::new() ) );
+		assert_eq!(
+			Names::new().with_forenames( &[ "Penelope", "Karin" ] ).with_surname( "Würzinger" ).with_gender( Gender::Female ).to_compact(),
+			"v1|Penelope,Karin|||Würzinger|||||||||||female|||".to_string()
+		);
+		assert_eq!(
+			Names::from_compact( "v1|Penelope,Karin|||Würzinger" ),
+			Ok( Names::new().with_forenames( &[ "Penelope", "Karin" ] ).with_surname( "Würzinger" ) )
+		);
+		assert_eq!(
+			Names::from_compact( "v1| Penelope ,|| |Würzinger" ),
+			Ok( Names::new().with_forenames( &[ "Penelope" ] ).with_surname( "Würzinger" ) )
+		);
+		assert_eq!( Names::from_compact( "Penelope|Würzinger" ), Err( NameError::IllegalCombo ) );
+		assert_eq!( Names::from_compact( "v2|Penelope|||Würzinger" ), Err( NameError::IllegalCombo ) );
+		assert_eq!( Names::from_compact( &format!( "v1{}", "|".repeat( COMPACT_FIELDS + 1 ) ) ), Err( NameError::IllegalCombo ) );
+		assert!( Names::from_compact( "v1|||||||||||||||unknown" ).is_err() );
+		assert_eq!( Names::from_compact( "v1|Penelope||von" ), Err( NameError::MissingNameElement( "surname".to_string() ) ) );
+	}
+
+	#[test]
+	fn names_forename_initials() {
+		let name = Names::new().with_forenames( &[ "Penelope", "Karin" ] );
+		assert_eq!(
+			name.forename_initials().collect::<Vec<( &str, String )>>(),
+			vec![ ( "Penelope", "P.".to_string() ), ( "Karin", "K.".to_string() ) ]
+		);
+
+		let name = Names::new().with_forenames( &[ "Hans-Peter" ] );
+		assert_eq!(
+			name.forename_initials().collect::<Vec<( &str, String )>>(),
+			vec![ ( "Hans-Peter", "H.-P.".to_string() ) ]
+		);
+		assert_eq!( Names::new().forename_initials().count(), 0 );
+	}
+
+	#[test]
+	fn names_predicate_only() {
+		use unic_langid::langid;
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+
+		let name = Names::new()
+			.with_forenames( &[ "Ulrich" ] )
+			.with_predicate( "von" );
+
+		assert_eq!( name.surname_full(), None );
+		assert_eq!( name.validate(), Err( NameError::MissingNameElement( "surname".to_string() ) ) );
+		assert_eq!(
+			name.designate( NameCombo::Name, GrammaticalCase::Nominative, &GERMAN ),
+			Err( NameError::CannotExpress { combo: NameCombo::Name, missing: "surname".to_string() } )
+		);
+		assert_eq!(
+			name.designate( NameCombo::Firstname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Ulrich".to_string()
+		);
+
+		let name = name.with_surname_parts( "von Lichtenstein" );
+		assert_eq!( name.surname_full(), Some( "von Lichtenstein".to_string() ) );
+		assert_eq!( name.validate(), Ok( () ) );
+	}
+
+	#[test]
+	fn name_strings_ordered_predicate_only() {
+		use unic_langid::langid;
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+
+		let name = Names::new()
+			.with_forenames( &[ "Penelope" ] )
+			.with_predicate( "von" )
+			.with_title( "Dr." );
+
+		for combo in [ NameCombo::OrderedName, NameCombo::OrderedNameLibrary, NameCombo::OrderedSurname, NameCombo::OrderedTitleName ] {
+			assert_eq!(
+				name.designate( combo, GrammaticalCase::Nominative, &GERMAN ),
+				Err( NameError::CannotExpress { combo, missing: "surname".to_string() } )
+			);
+			assert_eq!( name.missing_for( combo ), vec![ "surname" ] );
+		}
+
+		let name = name.with_surname( "Würzinger" );
+		assert_eq!(
+			name.designate( NameCombo::OrderedSurname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Würzinger, von".to_string()
+		);
+	}
+
+	#[test]
+	fn names_designate_or() {
+		use unic_langid::langid;
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+
+		let name = Names::new()
+			.with_forenames( &[ "Penelope" ] )
+			.with_surname( "Würzinger" );
+
+		assert_eq!(
+			name.designate_or( NameCombo::PoliteName, GrammaticalCase::Nominative, &GERMAN, NameCombo::Name ).unwrap(),
+			"Penelope Würzinger".to_string()
+		);
+		assert_eq!(
+			name.clone().with_gender( Gender::Female ).designate_or( NameCombo::PoliteName, GrammaticalCase::Nominative, &GERMAN, NameCombo::Name ).unwrap(),
+			"Frau Penelope Würzinger".to_string()
+		);
+		assert_eq!(
+			name.designate_or( NameCombo::PoliteName, GrammaticalCase::Nominative, &GERMAN, NameCombo::Nickname ),
+			Err( NameError::MissingNameElement( "gender/nickname".to_string() ) )
+		);
+	}
+
+	#[test]
+	fn names_designate_opts() {
+		use unic_langid::langid;
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+
+		let name = Names::new()
+			.with_forenames( &[ "Thomas" ] )
+			.with_predicate( "von" )
+			.with_surname( "Würzinger" )
+			.with_title( "Dr." )
+			.with_nickname( "Würzi" );
+
+		assert_eq!( DesignateOptions::default(), DesignateOptions::new( GrammaticalCase::Nominative, &langid!( "en-US" ) ) );
+
+		let opts = DesignateOptions {
+			initials_style: InitialsStyle::KeepParticles,
+			nickname_style: NicknameStyle::Parenthesized,
+			order_style: OrderStyle::PredicateBeforeSurname,
+			..DesignateOptions::new( GrammaticalCase::Genetive, &GERMAN )
+		};
+		assert_eq!( name.designate_opts( NameCombo::Initials, &opts ).unwrap(), "T. von W.".to_string() );
+		assert_eq!( name.designate_opts( NameCombo::TitleInitialsShort, &opts ).unwrap(), "Dr. T. von W.".to_string() );
+		assert_eq!( name.designate_opts( NameCombo::FirstNickname, &opts ).unwrap(), "Thomas' (Würzi)".to_string() );
+		assert_eq!( name.designate_opts( NameCombo::OrderedName, &opts ).unwrap(), "von Würzingers, Thomas".to_string() );
+		assert_eq!( name.designate_opts( NameCombo::Name, &opts ).unwrap(), name.designate( NameCombo::Name, GrammaticalCase::Genetive, &GERMAN ).unwrap() );
+		assert_eq!( name.designate_opts( NameCombo::TitleName, &opts ).unwrap(), "Dr. Thomas von Würzingers".to_string() );
+
+		let opts = DesignateOptions {
+			initials_style: InitialsStyle::DropParticles,
+			..DesignateOptions::default()
+		};
+		assert_eq!( name.designate_opts( NameCombo::Initials, &opts ).unwrap(), "T. W.".to_string() );
+	}
+
+	#[test]
+	fn names_nominative() {
+		use unic_langid::langid;
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+
+		let name = Names::new()
+			.with_forenames( &[ "Penelope" ] )
+			.with_predicate( "von" )
+			.with_surname( "Würzinger" );
+
+		assert_eq!( name.nominative( NameCombo::Name, &GERMAN ).unwrap(), "Penelope von Würzinger" );
+		assert_eq!( name.nominative( NameCombo::Surname, &GERMAN ), name.designate( NameCombo::Surname, GrammaticalCase::Nominative, &GERMAN ) );
+		assert!( name.nominative( NameCombo::Nickname, &GERMAN ).is_err() );
+	}
+
+	#[test]
+	fn names_with_gender_by_value() {
+		assert_eq!( Names::new().with_gender( Gender::Female ).gender(), &Some( Gender::Female ) );
+		#[allow( clippy::needless_borrows_for_generic_args )]
+		let name = Names::new().with_gender( &Gender::Male );
+		assert_eq!( Names::new().with_gender( Gender::Male ), name );
+	}
+
+	#[test]
+	fn name_strings_firstname_middle_initials() {
+		use unic_langid::langid;
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
 
-		assert_eq!(
-			name.designate( NameCombo::Supername, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Würzt-das-Essen".to_string()
-		);
+		let name = Names::new()
+			.with_forenames( &[ "Penelope", "Karin", "Anna" ] )
+			.with_predicate( "von" )
+			.with_surname( "Würzinger" );
 
 		assert_eq!(
-			name.designate( NameCombo::FirstSupername, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Thomas Würzt-das-Essen".to_string()
+			name.designate( NameCombo::FirstnameMiddleInitials, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Penelope K. A. von Würzinger".to_string()
 		);
-
 		assert_eq!(
-			name.designate( NameCombo::SuperName, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Thomas Würzt-das-Essen von Würzinger".to_string()
+			name.designate( NameCombo::FirstnameMiddleInitials, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"Penelope K. A. von Würzingers".to_string()
 		);
-
 		assert_eq!(
-			name.designate( NameCombo::PoliteSupername, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Herr Würzt-das-Essen".to_string()
+			name.clone().with_forenames( &[ "Penelope" ] ).designate( NameCombo::FirstnameMiddleInitials, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Penelope von Würzinger".to_string()
 		);
-
 		assert_eq!(
-			name.designate( NameCombo::RankSupername, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Hauptkommissar Würzt-das-Essen".to_string()
+			Names { surname: None, ..name }.designate( NameCombo::FirstnameMiddleInitials, GrammaticalCase::Nominative, &GERMAN ),
+			Err( NameError::CannotExpress { combo: NameCombo::FirstnameMiddleInitials, missing: "surname".to_string() } )
 		);
 	}
 
 	#[test]
-	fn name_strings_female() {
+	fn name_strings_ordered_fullname() {
 		use unic_langid::langid;
-
 		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
 
-		// Penelope Karin von Würzinger geb. Stauff
-		let name = Names {
-			forenames: [ "Penelope", "Karin" ].iter().map( |x| x.to_string() ).collect(),
-			predicate: Some( "von".to_string() ),
-			surname: Some( "Würzinger".to_string() ),
-			birthname: Some( "Stauff".to_string() ),
-			title: Some( "Dr.".to_string() ),
-			rank: Some( "Majorin".to_string() ),
-			nickname: None,
-			honorname: Some( "Große".to_string() ),
-			supername: None,
-			gender: Some( Gender::Female ),
-		};
+		let name = Names::new()
+			.with_forenames( &[ "Penelope", "Karin" ] )
+			.with_predicate( "von" )
+			.with_surname( "Würzinger" )
+			.with_birthname( "Stauff" );
 
 		assert_eq!(
-			name.designate( NameCombo::Name, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Penelope von Würzinger".to_string()
+			name.designate( NameCombo::OrderedFullname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Würzinger geb. Stauff, Penelope Karin von".to_string()
 		);
 		assert_eq!(
-			name.designate( NameCombo::Name, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
-			"Penelope von Würzingers".to_string()
+			name.designate_opts( NameCombo::OrderedFullname, &DesignateOptions {
+				order_style: OrderStyle::PredicateBeforeSurname,
+				..DesignateOptions::new( GrammaticalCase::Nominative, &GERMAN )
+			} ).unwrap(),
+			"von Würzinger geb. Stauff, Penelope Karin".to_string()
 		);
 		assert_eq!(
-			name.designate( NameCombo::Name, GrammaticalCase::Accusative, &GERMAN ).unwrap(),
-			"Penelope von Würzinger".to_string()
+			Names { birthname: None, ..name.clone() }.designate( NameCombo::OrderedFullname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Würzinger, Penelope Karin von".to_string()
 		);
-
 		assert_eq!(
-			name.designate( NameCombo::Surname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"von Würzinger".to_string()
+			Names { surname: None, ..name }.designate( NameCombo::OrderedFullname, GrammaticalCase::Nominative, &GERMAN ),
+			Err( NameError::CannotExpress { combo: NameCombo::OrderedFullname, missing: "surname".to_string() } )
 		);
+	}
 
-		assert_eq!(
-			name.designate( NameCombo::Firstname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Penelope".to_string()
-		);
+	#[test]
+	fn names_into_string() {
+		let name = Names::new()
+			.with_forenames( &[ "Penelope" ] )
+			.with_predicate( "von" )
+			.with_surname( "Würzinger" );
+
+		let text: String = ( &name ).into();
+		assert_eq!( text, "Penelope von Würzinger".to_string() );
+		let text: String = name.into();
+		assert_eq!( text, "Penelope von Würzinger".to_string() );
+		assert_eq!( String::from( Names::new() ), String::new() );
+	}
 
-		assert_eq!(
-			name.designate( NameCombo::Fullname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Penelope Karin von Würzinger geb. Stauff".to_string()
-		);
+	#[test]
+	fn names_hyphenated_forename() {
+		use unic_langid::langid;
+		const FRENCH: LanguageIdentifier = langid!( "fr-FR" );
 
+		let name = Names::new()
+			.with_hyphenated_forename( &[ "Jean", "Pierre" ] )
+			.with_surname( "Dupont" );
+		assert_eq!( name.forenames(), &vec![ "Jean-Pierre".to_string() ] );
 		assert_eq!(
-			name.designate( NameCombo::Title, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Dr.".to_string()
+			name.designate( NameCombo::Firstname, GrammaticalCase::Nominative, &FRENCH ).unwrap(),
+			"Jean-Pierre".to_string()
 		);
 
-		assert_eq!(
-			name.designate( NameCombo::TitleName, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Dr. Penelope von Würzinger".to_string()
-		);
+		let name = name.with_hyphenated_forename( &[ "Marie", " " ] );
+		assert_eq!( name.forenames(), &vec![ "Jean-Pierre".to_string(), "Marie".to_string() ] );
+		assert_eq!( Names::new().with_hyphenated_forename( &[ "" ] ), Names::new() );
+	}
 
-		assert_eq!(
-			name.designate( NameCombo::TitleFirstname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Dr. Penelope".to_string()
-		);
+	#[test]
+	fn test_regnal_value() {
+		assert_eq!( regnal_value( "VIII" ), Some( 8 ) );
+		assert_eq!( regnal_value( "XIV." ), Some( 14 ) );
+		assert_eq!( regnal_value( "ii" ), Some( 2 ) );
+		assert_eq!( regnal_value( "14" ), Some( 14 ) );
+		assert_eq!( regnal_value( "Great" ), None );
+		assert_eq!( regnal_value( "MCMXCIV" ), Some( 1994 ) );
+		assert_eq!( regnal_value( "IIIIIIV" ), None );
+		assert_eq!( regnal_value( "IC" ), None );
+		assert_eq!( regnal_value( &"M".repeat( 5_000_000 ) ), None );
+		assert_eq!( regnal_value( "99999999999" ), None );
+		assert_eq!( regnal_value( "IXI" ), None );
+		assert_eq!( regnal_value( "IVI" ), None );
+		assert_eq!( regnal_value( "IIII" ), None );
+		assert_eq!( regnal_value( &"M".repeat( 43 ) ), None );
+		assert_eq!( regnal_value( "MMMCMXCIX" ), Some( 3999 ) );
+		assert_eq!( regnal_value( "0" ), None );
+		assert_eq!( regnal_value( "+3" ), None );
+		assert_eq!( regnal_value( "-3" ), None );
+		assert_eq!( regnal_value( "" ), None );
+		assert_eq!( roman_numeral( 1994 ), Some( "MCMXCIV".to_string() ) );
+		assert_eq!( roman_numeral( 0 ), None );
+		assert_eq!( roman_numeral( 4000 ), None );
+		assert_eq!( ordinal_english( 8 ), Some( "Eighth" ) );
+		assert_eq!( ordinal_english( 0 ), None );
+		assert_eq!( ordinal_english( 21 ), None );
+	}
 
-		assert_eq!(
-			name.designate( NameCombo::TitleSurname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Dr. von Würzinger".to_string()
-		);
+	#[test]
+	fn name_strings_regnal() {
+		use unic_langid::langid;
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+		const US_ENGLISH: LanguageIdentifier = langid!( "en-US" );
 
-		assert_eq!(
-			name.designate( NameCombo::TitleFullname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Dr. Penelope Karin von Würzinger geb. Stauff".to_string()
-		);
+		let name = Names::new()
+			.with_forenames( &[ "Heinrich" ] )
+			.with_regnal( "VIII" )
+			.with_gender( Gender::Male );
 
 		assert_eq!(
-			name.designate( NameCombo::Polite, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Frau".to_string()
+			name.designate( NameCombo::Regnal, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
+			"Heinrich VIII.".to_string()
 		);
-
 		assert_eq!(
-			name.designate( NameCombo::PoliteName, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Frau Penelope von Würzinger".to_string()
+			name.designate( NameCombo::Regnal, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"Heinrichs VIII.".to_string()
 		);
-
 		assert_eq!(
-			name.designate( NameCombo::PoliteFirstname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Frau Penelope".to_string()
+			name.designate( NameCombo::RegnalSpelled, GrammaticalCase::Nominative, &GERMAN ),
+			Err( NameError::LangNotSupported( "de-DE".to_string() ) )
 		);
 
-		assert_eq!(
-			name.designate( NameCombo::PoliteSurname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Frau von Würzinger".to_string()
-		);
+		let name = Names::new()
+			.with_forenames( &[ "Elizabeth" ] )
+			.with_regnal( "II" )
+			.with_gender( Gender::Female );
 
 		assert_eq!(
-			name.designate( NameCombo::PoliteFullname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Frau Penelope Karin von Würzinger geb. Stauff".to_string()
+			name.designate( NameCombo::Regnal, GrammaticalCase::Nominative, &US_ENGLISH ).unwrap(),
+			"Elizabeth II".to_string()
 		);
-
 		assert_eq!(
-			name.designate( NameCombo::PoliteTitleName, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Frau Dr. Penelope von Würzinger".to_string()
+			name.designate( NameCombo::RegnalSpelled, GrammaticalCase::Nominative, &US_ENGLISH ).unwrap(),
+			"Elizabeth the Second".to_string()
 		);
-
 		assert_eq!(
-			name.designate( NameCombo::Rank, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Majorin".to_string()
+			name.designate( NameCombo::RegnalSpelled, GrammaticalCase::Genetive, &US_ENGLISH ).unwrap(),
+			"Elizabeth the Second's".to_string()
 		);
-
 		assert_eq!(
-			name.designate( NameCombo::PoliteRank, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Frau Majorin".to_string()
+			Names { regnal: None, ..name.clone() }.designate( NameCombo::Regnal, GrammaticalCase::Nominative, &US_ENGLISH ),
+			Err( NameError::CannotExpress { combo: NameCombo::Regnal, missing: "regnal".to_string() } )
 		);
+		assert!( matches!(
+			name.clone().with_regnal( "Great" ).designate( NameCombo::RegnalSpelled, GrammaticalCase::Nominative, &US_ENGLISH ),
+			Err( NameError::NotExpressionable( _ ) )
+		) );
+		for regnal in [ "IIII", "IXI", "0", "+3" ] {
+			assert!( matches!(
+				name.clone().with_regnal( regnal ).designate( NameCombo::RegnalSpelled, GrammaticalCase::Nominative, &US_ENGLISH ),
+				Err( NameError::NotExpressionable( _ ) )
+			), "{}", regnal );
+		}
+		assert!( matches!(
+			name.clone().with_regnal( "IIIIIIV" ).designate( NameCombo::RegnalSpelled, GrammaticalCase::Nominative, &US_ENGLISH ),
+			Err( NameError::NotExpressionable( _ ) )
+		) );
+		assert!( matches!(
+			name.with_regnal( &"M".repeat( 5_000_000 ) ).designate( NameCombo::RegnalSpelled, GrammaticalCase::Nominative, &US_ENGLISH ),
+			Err( NameError::NotExpressionable( _ ) )
+		) );
+	}
 
-		assert_eq!(
-			name.designate( NameCombo::RankName, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Majorin Penelope von Würzinger".to_string()
-		);
+	#[test]
+	fn names_designate_many() {
+		use unic_langid::langid;
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
 
-		assert_eq!(
-			name.designate( NameCombo::RankFirstname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Majorin Penelope".to_string()
-		);
+		let name = Names::new()
+			.with_forenames( &[ "Penelope" ] )
+			.with_surname( "Würzinger" );
 
 		assert_eq!(
-			name.designate( NameCombo::RankSurname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Majorin von Würzinger".to_string()
+			name.designate_many( &[ NameCombo::Surname, NameCombo::Nickname, NameCombo::Name ], GrammaticalCase::Nominative, &GERMAN ),
+			vec![
+				Ok( "Würzinger".to_string() ),
+				Err( NameError::CannotExpress { combo: NameCombo::Nickname, missing: "nickname".to_string() } ),
+				Ok( "Penelope Würzinger".to_string() ),
+			]
 		);
+		assert!( name.designate_many( &[], GrammaticalCase::Nominative, &GERMAN ).is_empty() );
+	}
 
-		assert_eq!(
-			name.designate( NameCombo::RankFullname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Majorin Penelope Karin von Würzinger geb. Stauff".to_string()
-		);
+	#[test]
+	fn names_designate_str() {
+		let name = Names::new()
+			.with_forenames( &[ "Penelope" ] )
+			.with_predicate( "von" )
+			.with_surname( "Würzinger" );
 
 		assert_eq!(
-			name.designate( NameCombo::RankTitleName, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Majorin Dr. Penelope von Würzinger".to_string()
+			name.designate_str( NameCombo::Name, GrammaticalCase::Genetive, "de" ).unwrap(),
+			"Penelope von Würzingers".to_string()
 		);
-
 		assert_eq!(
-			name.designate( NameCombo::Honor, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Große".to_string()
+			name.designate_str( NameCombo::Name, GrammaticalCase::Genetive, "en-US" ).unwrap(),
+			"Penelope von Würzinger's".to_string()
 		);
-
 		assert_eq!(
-			name.designate( NameCombo::Honortitle, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Die Große".to_string()
+			name.designate_str( NameCombo::Name, GrammaticalCase::Nominative, "not a language" ),
+			Err( NameError::LangNotSupported( "not a language".to_string() ) )
 		);
+	}
 
-		assert_eq!(
-			name.designate( NameCombo::FirstHonorname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Penelope die Große".to_string()
-		);
+	#[test]
+	fn names_designate_raw() {
+		use unic_langid::langid;
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
 
-		assert_eq!(
-			name.designate( NameCombo::OrderedName, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Würzinger, Penelope von".to_string()
-		);
+		let name = Names::new()
+			.with_forenames( &[ "Thomas" ] )
+			.with_surname( "Würzinger" );
+
+		let opts = DesignateOptions {
+			raw: true,
+			..DesignateOptions::new( GrammaticalCase::Genetive, &GERMAN )
+		};
+		assert_eq!( name.designate_opts( NameCombo::Surname, &opts ).unwrap(), "Würzinger".to_string() );
+		assert_eq!( name.designate_opts( NameCombo::Name, &opts ).unwrap(), "Thomas Würzinger".to_string() );
+		assert_eq!( name.designate( NameCombo::Surname, GrammaticalCase::Genetive, &GERMAN ).unwrap(), "Würzingers".to_string() );
+	}
+
+	#[test]
+	fn names_ordered_name_case() {
+		use unic_langid::langid;
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+
+		let name = Names::new()
+			.with_forenames( &[ "Penelope" ] )
+			.with_predicate( "von" )
+			.with_surname( "Würzinger" )
+			.with_birthname( "Stauff" );
 
 		assert_eq!(
-			name.designate( NameCombo::OrderedSurname, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Würzinger, von".to_string()
+			name.designate( NameCombo::OrderedName, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"Würzingers, Penelope von".to_string()
 		);
-
 		assert_eq!(
-			name.designate( NameCombo::OrderedTitleName, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Würzinger, Dr. Penelope von".to_string()
+			name.designate( NameCombo::OrderedNameLibrary, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"von Würzingers, Penelope".to_string()
 		);
-
 		assert_eq!(
-			name.designate( NameCombo::Initials, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"P. v. W.".to_string()
+			name.designate( NameCombo::OrderedFullname, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"Würzingers geb. Stauff, Penelope von".to_string()
 		);
-
 		assert_eq!(
-			name.designate( NameCombo::InitialsFull, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Dr. P. K. v. W.".to_string()
+			name.designate( NameCombo::OrderedSurname, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"Würzingers, von".to_string()
 		);
-
 		assert_eq!(
-			name.designate( NameCombo::Sign, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Dr. P. K. v. Würzinger".to_string()
+			name.clone().with_title( "Dr." ).designate( NameCombo::OrderedTitleName, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+			"Würzingers, Dr. Penelope von".to_string()
 		);
 	}
 
 	#[test]
-	fn name_strings_roman_male() {
+	fn names_callsign() {
 		use unic_langid::langid;
-
 		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
 
-		// Gaius Julius Caesar
-		let name = Names {
-			forenames: vec![ "Gaius".to_string() ],
-			predicate: None,
-			surname: Some( "Julius".to_string() ),
-			birthname: None,
-			title: None,
-			rank: None,
-			nickname: Some( "Caesar".to_string() ),
-			honorname: None,
-			supername: None,
-			gender: None,
-		};
+		let name = Names::new()
+			.with_forenames( &[ "Thomas" ] )
+			.with_surname( "Würzinger" )
+			.with_supername( "Würzt-das-Essen" )
+			.with_nickname( "Würzi" );
+		assert_eq!( name.designate( NameCombo::Callsign, GrammaticalCase::Genetive, &GERMAN ).unwrap(), "WÜRZI".to_string() );
+
+		let name = Names::new()
+			.with_forenames( &[ "Thomas" ] )
+			.with_supername( "Würzt-das-Essen" );
+		assert_eq!( name.designate( NameCombo::Callsign, GrammaticalCase::Nominative, &GERMAN ).unwrap(), "WÜRZT-DAS-ESSEN".to_string() );
+
+		let name = Names::new()
+			.with_forenames( &[ "Ilkin" ] );
+		assert_eq!( name.designate( NameCombo::Callsign, GrammaticalCase::Nominative, &GERMAN ).unwrap(), "ILKIN".to_string() );
+		assert_eq!( name.designate( NameCombo::Callsign, GrammaticalCase::Nominative, &langid!( "tr-TR" ) ).unwrap(), "ILKİN".to_string() );
+
+		let name = Names::new()
+			.with_surname( "Würzinger" );
+		assert!( !name.can_designate( NameCombo::Callsign ) );
+		assert_eq!( name.missing_for( NameCombo::Callsign ), vec![ "nickname", "supername", "forenames" ] );
+		assert!( name.designate( NameCombo::Callsign, GrammaticalCase::Nominative, &GERMAN ).is_err() );
+	}
 
-		assert_eq!(
-			name.designate( NameCombo::TriaNomina, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Gaius Julius Caesar".to_string()
+	#[test]
+	fn names_builder_opt() {
+		let title: Option<String> = Some( "Dr.".to_string() );
+		let predicate: Option<String> = None;
+		let nickname: Option<String> = None;
+
+		let name = Names::new()
+			.with_forenames( &[ "Thomas" ] )
+			.with_title_opt( title.as_deref() )
+			.with_predicate_opt( predicate.as_deref() )
+			.with_surname_opt( Some( "Würzinger" ) )
+			.with_nickname_opt( nickname.as_deref() )
+			.with_gender_opt( Some( Gender::Male ) );
+
+		assert_eq!( name, Names::new()
+			.with_forenames( &[ "Thomas" ] )
+			.with_title( "Dr." )
+			.with_surname( "Würzinger" )
+			.with_gender( Gender::Male )
 		);
+
+		let name = name
+			.with_surname_opt( None )
+			.with_gender_opt( None );
+		assert_eq!( name.surname(), Some( "Würzinger" ) );
+		assert_eq!( name.gender(), &Some( Gender::Male ) );
 	}
 
 	#[test]
-	fn name_strings_roman_female() {
+	fn names_surname_feminize() {
 		use unic_langid::langid;
+		const CZECH: LanguageIdentifier = langid!( "cs-CZ" );
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
 
+		let name = Names::new()
+			.with_forenames( &[ "Jana" ] )
+			.with_surname( "Novák" )
+			.with_gender( Gender::Female );
+		assert_eq!( name.designate( NameCombo::Name, GrammaticalCase::Nominative, &CZECH ).unwrap(), "Jana Novák".to_string() );
+
+		let name = name.with_surname_feminize( true );
+		assert_eq!( name.designate( NameCombo::Name, GrammaticalCase::Nominative, &CZECH ).unwrap(), "Jana Nováková".to_string() );
+		assert_eq!( name.designate( NameCombo::Surname, GrammaticalCase::Nominative, &langid!( "sk-SK" ) ).unwrap(), "Nováková".to_string() );
+		assert_eq!( name.designate( NameCombo::Name, GrammaticalCase::Nominative, &GERMAN ).unwrap(), "Jana Novák".to_string() );
+		assert_eq!( name.surname_full(), Some( "Novák".to_string() ) );
+
+		let name = name.with_surname( "Novotný" );
+		assert_eq!( name.designate( NameCombo::Surname, GrammaticalCase::Nominative, &CZECH ).unwrap(), "Novotná".to_string() );
+		let name = name.with_surname( "Svoboda" );
+		assert_eq!( name.designate( NameCombo::Surname, GrammaticalCase::Nominative, &CZECH ).unwrap(), "Svobodová".to_string() );
+		let name = name.with_surname( "Hájek" );
+		assert_eq!( name.designate( NameCombo::Surname, GrammaticalCase::Nominative, &CZECH ).unwrap(), "Hájková".to_string() );
+		let name = name.with_surname( "Nováková" );
+		assert_eq!( name.designate( NameCombo::Surname, GrammaticalCase::Nominative, &CZECH ).unwrap(), "Nováková".to_string() );
+
+		let name = name.with_surname( "Novák" ).with_gender( Gender::Male );
+		assert_eq!( name.designate( NameCombo::Surname, GrammaticalCase::Nominative, &CZECH ).unwrap(), "Novák".to_string() );
+	}
+
+	#[test]
+	fn names_rufname() {
+		use unic_langid::langid;
 		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
 
-		// Iunia Prima
-		let name = Names {
-			forenames: Vec::new(),
-			predicate: None,
-			surname: Some( "Iunia".to_string() ),
-			birthname: None,
-			title: None,
-			rank: None,
-			nickname: Some( "Prima".to_string() ),
-			honorname: None,
-			supername: None,
-			gender: None,
-		};
+		let name = Names::new()
+			.with_forenames( &[ "Penelope", "Karin" ] )
+			.with_predicate( "von" )
+			.with_surname( "Würzinger" )
+			.with_gender( Gender::Female );
+		assert_eq!( name.rufname_index(), None );
+		assert_eq!( name.designate( NameCombo::Firstname, GrammaticalCase::Nominative, &GERMAN ).unwrap(), "Penelope".to_string() );
+
+		let name = name.with_rufname_index( 1 );
+		assert_eq!( name.rufname_index(), Some( 1 ) );
+		assert_eq!( name.validate(), Ok( () ) );
+		assert_eq!( name.designate( NameCombo::Firstname, GrammaticalCase::Nominative, &GERMAN ).unwrap(), "Karin".to_string() );
+		assert_eq!( name.designate( NameCombo::Firstname, GrammaticalCase::Genetive, &GERMAN ).unwrap(), "Karins".to_string() );
+		assert_eq!( name.designate( NameCombo::Name, GrammaticalCase::Nominative, &GERMAN ).unwrap(), "Karin von Würzinger".to_string() );
+		assert_eq!( name.designate( NameCombo::PoliteFirstname, GrammaticalCase::Nominative, &GERMAN ).unwrap(), "Frau Karin".to_string() );
+		assert_eq!( name.designate( NameCombo::Fullname, GrammaticalCase::Nominative, &GERMAN ).unwrap(), "Penelope Karin von Würzinger".to_string() );
+
+		let name = name.with_rufname_index( 2 );
+		assert!( matches!( name.validate(), Err( NameError::NotExpressionable( _ ) ) ) );
+		assert!( matches!( name.designate( NameCombo::Firstname, GrammaticalCase::Nominative, &GERMAN ), Err( NameError::NotExpressionable( _ ) ) ) );
+		assert!( matches!( name.designate( NameCombo::Name, GrammaticalCase::Nominative, &GERMAN ), Err( NameError::NotExpressionable( _ ) ) ) );
+
+		assert_eq!( Names::from_compact( "v1||||||||||||||||||x" ), Err( NameError::IllegalCombo ) );
+	}
+
+	#[test]
+	fn names_designate_missing_error() {
+		use unic_langid::langid;
+		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
 
+		for combo in NameCombo::ALL {
+			assert!(
+				!matches!( Names::new().designate( *combo, GrammaticalCase::Nominative, &GERMAN ), Err( NameError::MissingNameElement( _ ) ) ),
+				"{}", combo
+			);
+		}
 		assert_eq!(
-			name.designate( NameCombo::DuaNomina, GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Iunia Prima".to_string()
+			Names::new().with_predicate( "von" ).validate(),
+			Err( NameError::MissingNameElement( "surname".to_string() ) )
 		);
 	}
 
 	#[test]
-	fn name_moniker() {
+	fn names_initials_no_particle() {
 		use unic_langid::langid;
-
 		const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+		const US_ENGLISH: LanguageIdentifier = langid!( "en-US" );
 
-		assert_eq!( Names::new().moniker( GrammaticalCase::Nominative, &GERMAN ), Err( NameError::MissingNameElement( "supername".to_string() ) ) );
-		assert_eq!(
-			Names::new()
-				.with_forenames( &[ "Penelope", "Karin" ] )
-				.moniker( GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Penelope".to_string()
-		);
-		assert_eq!(
-			Names::new()
-				.with_forenames( &[ "Penelope", "Karin" ] )
-				.with_surname( "Würzinger" )
-				.moniker( GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Penelope Karin Würzinger".to_string()
-		);
-		assert_eq!(
-			Names::new()
-				.with_forenames( &[ "Penelope", "Karin" ] )
-				.with_predicate( "von" )
-				.with_surname( "Würzinger" )
-				.moniker( GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Penelope Karin von Würzinger".to_string()
-		);
-		assert_eq!(
-			Names::new()
-				.with_nickname( "Würzli" )
-				.moniker( GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Würzli".to_string()
-		);
-		assert_eq!(
-			Names::new()
-				.with_nickname( "Würzli" )
-				.with_surname( "Würzinger" )
-				.moniker( GrammaticalCase::Nominative, &GERMAN ).unwrap(),
-			"Würzinger".to_string()
-		);
+		let name = Names::new()
+			.with_forenames( &[ "John", "Fitzgerald" ] )
+			.with_surname( "Kennedy" )
+			.with_suffix( "Jr." );
+		assert_eq!( name.designate( NameCombo::InitialsNoParticle, GrammaticalCase::Nominative, &US_ENGLISH ).unwrap(), "J. K.".to_string() );
+
+		let name = Names::new()
+			.with_forenames( &[ "Penelope", "Karin" ] )
+			.with_rufname_index( 1 )
+			.with_predicate( "von" )
+			.with_surname( "Würzinger" );
+		assert_eq!( name.designate( NameCombo::InitialsNoParticle, GrammaticalCase::Nominative, &GERMAN ).unwrap(), "P. W.".to_string() );
+		assert_eq!( name.designate( NameCombo::Initials, GrammaticalCase::Nominative, &GERMAN ).unwrap(), "P. v. W.".to_string() );
+		assert_eq!( name.designate( NameCombo::InitialsNoParticle, GrammaticalCase::Nominative, &langid!( "hu-HU" ) ).unwrap(), "P. W.".to_string() );
+	}
+
+	#[test]
+	fn names_designate_raw_verbatim() {
+		use unic_langid::langid;
+
+		let raw = |locale: LanguageIdentifier| DesignateOptions {
+			raw: true,
+			..DesignateOptions::new( GrammaticalCase::Genetive, &locale )
+		};
+
+		let name = Names::new()
+			.with_forenames( &[ "Jana" ] )
+			.with_surname( "Novák" )
+			.with_surname_feminize( true )
+			.with_gender( Gender::Female );
+		assert_eq!( name.designate_opts( NameCombo::Name, &raw( langid!( "cs-CZ" ) ) ).unwrap(), "Jana Novák".to_string() );
+
+		let name = Names::new()
+			.with_forenames( &[ "Petra" ] )
+			.with_surname( "Würzinger" )
+			.with_rank( "Major" )
+			.with_rank_feminize( true )
+			.with_gender( Gender::Female );
+		assert_eq!( name.designate_opts( NameCombo::RankName, &raw( langid!( "de-DE" ) ) ).unwrap(), "Major Petra Würzinger".to_string() );
+		assert_eq!( name.designate( NameCombo::RankName, GrammaticalCase::Nominative, &langid!( "de-DE" ) ).unwrap(), "Majorin Petra Würzinger".to_string() );
+
+		let name = Names::new()
+			.with_forenames( &[ "Jan" ] )
+			.with_predicate( "van der" )
+			.with_surname( "Berg" )
+			.with_gender( Gender::Male );
+		assert_eq!( name.designate_opts( NameCombo::Surname, &raw( langid!( "nl-NL" ) ) ).unwrap(), "van der Berg".to_string() );
+		assert_eq!( name.designate_opts( NameCombo::PoliteSurname, &raw( langid!( "nl-NL" ) ) ).unwrap(), "meneer van der Berg".to_string() );
+		assert_eq!( name.designate( NameCombo::PoliteSurname, GrammaticalCase::Nominative, &langid!( "nl-NL" ) ).unwrap(), "meneer Van der Berg".to_string() );
 	}
 }
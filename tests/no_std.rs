@@ -0,0 +1,45 @@
+//! Checks that the crate can be used by a `no_std` crate relying only on `alloc`. Run with `cargo test --no-default-features` to build `name_combo` without `std`.
+#![no_std]
+
+
+
+
+//=============================================================================
+// Crates
+
+
+extern crate alloc;
+
+use alloc::string::ToString;
+
+use unic_langid::{LanguageIdentifier, langid};
+
+use name_combo::{Gender, GrammaticalCase, NameCombo, NameError, Names};
+
+
+
+
+//=============================================================================
+// Testing
+
+
+const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+
+
+#[test]
+fn designate_no_std() {
+	let name = Names::new()
+		.with_forenames( &[ "Penelope" ] )
+		.with_predicate( "von" )
+		.with_surname( "Würzinger" )
+		.with_gender( Gender::Female );
+
+	assert_eq!(
+		name.designate( NameCombo::PoliteName, GrammaticalCase::Genetive, &GERMAN ).unwrap(),
+		"Frau Penelope von Würzingers".to_string()
+	);
+	assert_eq!(
+		name.designate( NameCombo::Nickname, GrammaticalCase::Nominative, &GERMAN ),
+		Err( NameError::CannotExpress { combo: NameCombo::Nickname, missing: "nickname".to_string() } )
+	);
+}